@@ -0,0 +1,9 @@
+// Copyright 2025 Redglyph
+//
+
+fn main() {
+    // `Saturating<T>` is stable since Rust 1.74, above the MSRV
+    let ac = autocfg::new();
+    ac.emit_type_cfg("core::num::Saturating<u8>", "has_saturating");
+    autocfg::rerun_path("build.rs");
+}
@@ -0,0 +1,121 @@
+// Copyright 2025 Redglyph
+//
+
+use core::fmt::{Display, Formatter, Write};
+use core::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// The case of the letters of a label.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LetterCase {
+    /// `a`, `b`, `c`, ...
+    Lower,
+    /// `A`, `B`, `C`, ...
+    Upper,
+}
+
+/// An alphabetic label in bijective base 26: `a`, `b`, ..., `z`, `aa`, `ab`, ...
+///
+/// The label is only rendered when it's displayed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Alpha {
+    position: usize,
+    case: LetterCase,
+}
+
+impl Alpha {
+    /// Parses a label made of letters of the same case, like `"c"` or `"AB"`. Returns `None` if the
+    /// label is empty, contains other characters, mixes both cases, or is too large.
+    pub fn parse(label: &str) -> Option<Alpha> {
+        let case = if label.bytes().all(|b| b.is_ascii_lowercase()) {
+            LetterCase::Lower
+        } else if label.bytes().all(|b| b.is_ascii_uppercase()) {
+            LetterCase::Upper
+        } else {
+            return None;
+        };
+        let mut position = 0_usize;
+        for b in label.bytes() {
+            let digit = (b.to_ascii_lowercase() - b'a' + 1) as usize;
+            position = position.checked_mul(26)?.checked_add(digit)?;
+        }
+        (position > 0).then_some(Alpha { position, case })
+    }
+
+    /// Returns the position of the label, `a` being 1.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the case of the letters.
+    pub fn case(&self) -> LetterCase {
+        self.case
+    }
+}
+
+impl Display for Alpha {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let base = match self.case {
+            LetterCase::Lower => b'a',
+            LetterCase::Upper => b'A',
+        };
+        // enough for usize::MAX in base 26
+        let mut digits = [0_u8; 14];
+        let mut len = 0;
+        let mut n = self.position;
+        while n > 0 {
+            n -= 1;
+            digits[len] = base + (n % 26) as u8;
+            n /= 26;
+            len += 1;
+        }
+        for &d in digits[..len].iter().rev() {
+            f.write_char(d as char)?;
+        }
+        Ok(())
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields an [`Alpha`] label and the iteration item.
+///
+/// This `struct` is created by the [`index_alpha`](crate::IndexerIterator::index_alpha)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct AlphaIndexer<I> {
+    iter: I,
+    next: Alpha,
+}
+
+impl<I> AlphaIndexer<I> {
+    pub fn new(iter: I, start: Alpha) -> AlphaIndexer<I> {
+        AlphaIndexer { iter, next: start }
+    }
+}
+
+impl<I: Iterator> Iterator for AlphaIndexer<I> {
+    type Item = (Alpha, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let v = self.iter.next()?;
+        let result = Some((self.next, v));
+        self.next.position += 1;
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for AlphaIndexer<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for AlphaIndexer<I> {}
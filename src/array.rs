@@ -0,0 +1,59 @@
+// Copyright 2025 Redglyph
+//
+
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// An extension trait which indexes the values of an array into another array of the same length,
+/// on the stack, without going through an iterator and a heap-allocated collection.
+///
+/// # Examples
+///
+/// ```
+/// use iter_index::IndexedArray;
+///
+/// const NAMES: [&str; 3] = ["zero", "one", "two"];
+///
+/// let table = NAMES.index_array::<u8>();
+/// assert_eq!(table, [(0, "zero"), (1, "one"), (2, "two")]);
+///
+/// let table = NAMES.index_array_step::<i16>(-10, 10);
+/// assert_eq!(table, [(-10, "zero"), (0, "one"), (10, "two")]);
+/// ```
+pub trait IndexedArray<V, const N: usize> {
+    /// Returns an array of pairs `(i, val)`, where `i` is of type `T` and starts at 0 and increments by 1,
+    /// and `val` is the corresponding value of the original array.
+    fn index_array<T>(self) -> [(T, V); N]
+    where
+        T: IndexValue,
+        u8: Into<T>;
+
+    /// Returns an array of pairs `(i, val)`, where `i` is of type `T` and starts at `start` and increments
+    /// by `step`, and `val` is the corresponding value of the original array.
+    fn index_array_step<T>(self, start: T, step: T) -> [(T, V); N]
+    where
+        T: IndexValue;
+}
+
+impl<V, const N: usize> IndexedArray<V, N> for [V; N] {
+    fn index_array<T>(self) -> [(T, V); N]
+    where
+        T: IndexValue,
+        u8: Into<T>,
+    {
+        self.index_array_step(0.into(), 1.into())
+    }
+
+    fn index_array_step<T>(self, start: T, step: T) -> [(T, V); N]
+    where
+        T: IndexValue,
+    {
+        let mut counter = start;
+        self.map(|v| {
+            let i = counter.clone();
+            counter.add_assign_ref(&step);
+            (i, v)
+        })
+    }
+}
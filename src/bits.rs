@@ -0,0 +1,73 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// The order in which the bits of each byte are read by [`BitIndexer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BitOrder {
+    /// The most significant bit first.
+    MsbFirst,
+    /// The least significant bit first.
+    LsbFirst,
+}
+
+/// An iterator that yields the bits of a byte iterator along with their absolute position.
+///
+/// This `struct` is created by the [`index_bits`](crate::IndexerIterator::index_bits)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct BitIndexer<I, T> {
+    iter: I,
+    order: BitOrder,
+    byte: u8,
+    remaining: u32,
+    counter: T,
+    step: T,
+}
+
+impl<I, T> BitIndexer<I, T> {
+    pub fn new(iter: I, order: BitOrder, start: T, step: T) -> BitIndexer<I, T> {
+        BitIndexer { iter, order, byte: 0, remaining: 0, counter: start, step }
+    }
+}
+
+impl<I, T> Iterator for BitIndexer<I, T>
+where
+    I: Iterator<Item = u8>,
+    T: IndexValue,
+{
+    type Item = (T, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            self.byte = self.iter.next()?;
+            self.remaining = 8;
+        }
+        self.remaining -= 1;
+        let shift = match self.order {
+            BitOrder::MsbFirst => self.remaining,
+            BitOrder::LsbFirst => 7 - self.remaining,
+        };
+        let result = Some((self.counter.clone(), (self.byte >> shift) & 1 != 0));
+        self.counter.add_assign_ref(&self.step);
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        let bits = |n: usize| n.checked_mul(8).and_then(|n| n.checked_add(self.remaining as usize));
+        (bits(lo).unwrap_or(usize::MAX), hi.and_then(bits))
+    }
+}
+
+impl<I, T> FusedIterator for BitIndexer<I, T>
+where
+    I: FusedIterator<Item = u8>,
+    T: IndexValue,
+{}
@@ -0,0 +1,121 @@
+// Copyright 2025 Redglyph
+//
+
+use std::io::{self, ErrorKind, Read};
+use std::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// What to do with the last block of a reader when it's shorter than the block size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShortBlock {
+    /// Drops the incomplete block.
+    Discard,
+    /// Pads the incomplete block with the given byte.
+    Pad(u8),
+    /// Yields an error of kind [`ErrorKind::UnexpectedEof`] instead of the incomplete block.
+    Error,
+}
+
+/// An extension trait on [`Read`] which reads fixed-size blocks along with their offset.
+pub trait IndexerRead: Read {
+    /// Creates an iterator which reads the source in blocks of `N` bytes, giving the offset of each
+    /// block in the source as well as the block itself.
+    ///
+    /// The iterator yields pairs `(offset, block)`, where `offset` is the position of the first byte of
+    /// the block, counting from 0 at the current position of the reader, and `block` is an
+    /// `io::Result<[u8; N]>`. The last block is handled according to `short`, if the source ends
+    /// in the middle of a block.
+    ///
+    /// Reads interrupted by [`ErrorKind::Interrupted`] are retried. After any other error, the iterator
+    /// yields `None`.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::{IndexerRead, ShortBlock};
+    ///
+    /// let data: &[u8] = b"abcdefghij";
+    /// let result = data.index_blocks::<4>(ShortBlock::Pad(b'.'))
+    ///     .map(|(offset, block)| (offset, block.unwrap()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![(0, *b"abcd"), (4, *b"efgh"), (8, *b"ij..")]);
+    /// ```
+    fn index_blocks<const N: usize>(self, short: ShortBlock) -> IndexedBlocks<Self, N> where Self: Sized {
+        IndexedBlocks::new(self, short)
+    }
+}
+
+impl<R: Read> IndexerRead for R {}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that reads blocks of `N` bytes and yields their offset and content.
+///
+/// This `struct` is created by the [`index_blocks`](IndexerRead::index_blocks)
+/// method on [`IndexerRead`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IndexedBlocks<R, const N: usize> {
+    reader: R,
+    offset: u64,
+    short: ShortBlock,
+    done: bool,
+}
+
+impl<R, const N: usize> IndexedBlocks<R, N> {
+    pub fn new(reader: R, short: ShortBlock) -> IndexedBlocks<R, N> {
+        assert!(N != 0, "N must be greater than 0");
+        IndexedBlocks { reader, offset: 0, short, done: false }
+    }
+
+    /// Consumes the adapter and returns the reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read, const N: usize> Iterator for IndexedBlocks<R, N> {
+    type Item = (u64, io::Result<[u8; N]>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let offset = self.offset;
+        let mut block = [0; N];
+        let mut filled = 0;
+        while filled < N {
+            match self.reader.read(&mut block[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => {
+                    self.done = true;
+                    return Some((offset, Err(e)));
+                }
+            }
+        }
+        self.offset += filled as u64;
+        if filled == N {
+            return Some((offset, Ok(block)));
+        }
+        self.done = true;
+        match self.short {
+            _ if filled == 0 => None,
+            ShortBlock::Discard => None,
+            ShortBlock::Pad(byte) => {
+                block[filled..].fill(byte);
+                Some((offset, Ok(block)))
+            }
+            ShortBlock::Error => Some((offset, Err(io::Error::new(ErrorKind::UnexpectedEof, "incomplete last block")))),
+        }
+    }
+}
+
+impl<R: Read, const N: usize> FusedIterator for IndexedBlocks<R, N> {}
@@ -0,0 +1,76 @@
+// Copyright 2025 Redglyph
+//
+
+use std::time::{Duration, Instant};
+
+//------------------------------------------------------------------------------
+
+/// An iterator that passes the indexed items of the source iterator through until a time budget
+/// is exhausted, and which keeps track of the last index it yielded.
+///
+/// This `struct` is created by the [`within_budget`](crate::IndexerIterator::within_budget)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Budgeted<I, T> {
+    iter: I,
+    budget: Duration,
+    deadline: Option<Instant>,
+    last_index: Option<T>,
+    exhausted: bool,
+}
+
+impl<I, T> Budgeted<I, T> {
+    pub fn new(iter: I, budget: Duration) -> Budgeted<I, T> {
+        Budgeted { iter, budget, deadline: None, last_index: None, exhausted: false }
+    }
+
+    /// Returns the index of the last item yielded, or `None` if no item has been yielded yet.
+    pub fn last_index(&self) -> Option<&T> {
+        self.last_index.as_ref()
+    }
+
+    /// Returns `true` if the iteration was stopped because the budget was exhausted.
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// Gives a new time budget, which starts when the next item is requested. The iteration
+    /// continues where it stopped.
+    pub fn renew(&mut self, budget: Duration) {
+        self.budget = budget;
+        self.deadline = None;
+        self.exhausted = false;
+    }
+
+    /// Consumes the adapter and returns the source iterator, which can be used to resume the iteration.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I, T, V> Iterator for Budgeted<I, T>
+where
+    I: Iterator<Item = (T, V)>,
+    T: Clone,
+{
+    type Item = (T, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let now = Instant::now();
+        let deadline = *self.deadline.get_or_insert_with(|| now + self.budget);
+        self.exhausted = now >= deadline;
+        if self.exhausted {
+            return None;
+        }
+        let (i, v) = self.iter.next()?;
+        self.last_index = Some(i.clone());
+        Some((i, v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the budget may run out at any time
+        (0, self.iter.size_hint().1)
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// The behaviour of [`IndexBy`] when the index iterator is exhausted before the source iterator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Exhaustion {
+    /// Ends the iteration.
+    Stop,
+    /// Panics.
+    Panic,
+    /// Gives the last index to all the remaining items. If the index iterator is empty, the iteration
+    /// ends.
+    RepeatLast,
+}
+
+/// An iterator that yields the next value of an index iterator and the iteration item.
+///
+/// This `struct` is created by the [`index_by`](crate::IndexerIterator::index_by)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IndexBy<I, J: Iterator> {
+    iter: I,
+    indices: J,
+    exhaustion: Exhaustion,
+    last: Option<J::Item>,
+}
+
+impl<I, J: Iterator> IndexBy<I, J> {
+    pub fn new(iter: I, indices: J, exhaustion: Exhaustion) -> IndexBy<I, J> {
+        IndexBy { iter, indices, exhaustion, last: None }
+    }
+}
+
+impl<I, J> Iterator for IndexBy<I, J>
+where
+    I: Iterator,
+    J: Iterator,
+    J::Item: Clone,
+{
+    type Item = (J::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the index is taken first, so the source item isn't lost when the iteration stops
+        let i = match (self.indices.next(), self.exhaustion) {
+            (Some(i), Exhaustion::RepeatLast) => {
+                self.last = Some(i.clone());
+                i
+            }
+            (Some(i), _) => i,
+            (None, Exhaustion::Stop) => return None,
+            (None, Exhaustion::Panic) => {
+                self.iter.next()?;
+                panic!("the index iterator is exhausted");
+            }
+            (None, Exhaustion::RepeatLast) => self.last.clone()?,
+        };
+        let item = self.iter.next()?;
+        Some((i, item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        match self.exhaustion {
+            Exhaustion::Stop => {
+                let (i_lo, i_hi) = self.indices.size_hint();
+                let hi = match (hi, i_hi) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (a, b) => a.or(b),
+                };
+                (lo.min(i_lo), hi)
+            }
+            Exhaustion::Panic => (lo, hi),
+            Exhaustion::RepeatLast => {
+                if self.last.is_some() {
+                    (lo, hi)
+                } else {
+                    // the index iterator may be empty
+                    (0, hi)
+                }
+            }
+        }
+    }
+}
+
+impl<I, J> FusedIterator for IndexBy<I, J>
+where
+    I: FusedIterator,
+    J: FusedIterator,
+    J::Item: Clone,
+{}
@@ -0,0 +1,160 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime};
+
+//------------------------------------------------------------------------------
+
+/// The step between the dates of a [`CalendarIndexer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CalendarStep {
+    /// A fixed duration. For a [`Date`], the fractional days are ignored.
+    Duration(Duration),
+    /// A number of calendar months, which may be negative. When the day doesn't exist in the target
+    /// month, like January 31 + 1 month, the last day of that month is used.
+    Months(i32),
+}
+
+impl CalendarStep {
+    /// A step of `n` calendar years.
+    pub fn years(n: i32) -> CalendarStep {
+        CalendarStep::Months(n * 12)
+    }
+}
+
+/// The date and time types of the `time` crate that can be used as indices by [`CalendarIndexer`].
+pub trait CalendarIndex: Copy {
+    /// Returns `self + duration`, or `None` if the result is out of range.
+    fn checked_add_duration(self, duration: Duration) -> Option<Self>;
+
+    /// Returns the calendar date.
+    fn date(self) -> Date;
+
+    /// Returns `self` with the date replaced by `date`.
+    fn with_date(self, date: Date) -> Self;
+}
+
+impl CalendarIndex for Date {
+    fn checked_add_duration(self, duration: Duration) -> Option<Self> {
+        self.checked_add(duration)
+    }
+
+    fn date(self) -> Date {
+        self
+    }
+
+    fn with_date(self, date: Date) -> Self {
+        date
+    }
+}
+
+macro_rules! impl_calendar_index {
+    ($($t:ty),*) => {$(
+        impl CalendarIndex for $t {
+            fn checked_add_duration(self, duration: Duration) -> Option<Self> {
+                self.checked_add(duration)
+            }
+
+            fn date(self) -> Date {
+                <$t>::date(self)
+            }
+
+            fn with_date(self, date: Date) -> Self {
+                self.replace_date(date)
+            }
+        }
+    )*};
+}
+
+impl_calendar_index!(PrimitiveDateTime, OffsetDateTime);
+
+/// Returns `date + months`, using the last day of the target month if the day doesn't exist.
+fn add_months(date: Date, months: i128) -> Option<Date> {
+    let (year, month, day) = date.to_calendar_date();
+    let total = year as i128 * 12 + (month as u8 - 1) as i128 + months;
+    let year = i32::try_from(total.div_euclid(12)).ok()?;
+    let month = Month::try_from(total.rem_euclid(12) as u8 + 1).ok()?;
+    // the day is clipped to the end of the month, which is at most 3 days before
+    (day.saturating_sub(3).max(1)..=day).rev().find_map(|day| Date::from_calendar_date(year, month, day).ok())
+}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields a date or a date and time of the `time` crate, and the iteration item.
+///
+/// This `struct` is created by the [`index_calendar`](crate::IndexerIterator::index_calendar)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CalendarIndexer<I, D> {
+    iter: I,
+    start: D,
+    step: CalendarStep,
+    // number of items taken from the front
+    count: usize,
+}
+
+impl<I, D: CalendarIndex> CalendarIndexer<I, D> {
+    pub fn new(iter: I, start: D, step: CalendarStep) -> CalendarIndexer<I, D> {
+        CalendarIndexer { iter, start, step, count: 0 }
+    }
+
+    /// Returns `start + n * step`. The date is calculated from the start each time, so stepping by
+    /// months doesn't drift when a day is clipped to the end of a shorter month.
+    fn date(&self, n: usize) -> D {
+        match self.step {
+            CalendarStep::Duration(step) => {
+                step.whole_nanoseconds().checked_mul(n as i128)
+                    .and_then(|nanos| {
+                        let secs = i64::try_from(nanos / 1_000_000_000).ok()?;
+                        self.start.checked_add_duration(Duration::new(secs, (nanos % 1_000_000_000) as i32))
+                    })
+            }
+            CalendarStep::Months(months) => {
+                add_months(self.start.date(), months as i128 * n as i128).map(|date| self.start.with_date(date))
+            }
+        }.expect("the index overflowed the date range")
+    }
+}
+
+impl<I: Iterator, D: CalendarIndex> Iterator for CalendarIndexer<I, D> {
+    type Item = (D, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.count += 1;
+        Some((self.date(self.count - 1), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let item = self.iter.nth(n)?;
+        self.count += n + 1;
+        Some((self.date(self.count - 1), item))
+    }
+}
+
+impl<I, D> DoubleEndedIterator for CalendarIndexer<I, D>
+where
+    I: ExactSizeIterator + DoubleEndedIterator,
+    D: CalendarIndex,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        Some((self.date(self.count + self.iter.len()), item))
+    }
+}
+
+impl<I: ExactSizeIterator, D: CalendarIndex> ExactSizeIterator for CalendarIndexer<I, D> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedIterator, D: CalendarIndex> FusedIterator for CalendarIndexer<I, D> {}
@@ -0,0 +1,65 @@
+// Copyright 2025 Redglyph
+//
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+//------------------------------------------------------------------------------
+
+/// An iterator that passes the indexed items of the source iterator through until a cancellation
+/// flag is set, and which keeps track of the last index it yielded.
+///
+/// This `struct` is created by the [`until_cancelled`](crate::IndexerIterator::until_cancelled)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Cancellable<'a, I, T> {
+    iter: I,
+    flag: &'a AtomicBool,
+    last_index: Option<T>,
+    cancelled: bool,
+}
+
+impl<'a, I, T> Cancellable<'a, I, T> {
+    pub fn new(iter: I, flag: &'a AtomicBool) -> Cancellable<'a, I, T> {
+        Cancellable { iter, flag, last_index: None, cancelled: false }
+    }
+
+    /// Returns the index of the last item yielded, or `None` if no item has been yielded yet.
+    pub fn last_index(&self) -> Option<&T> {
+        self.last_index.as_ref()
+    }
+
+    /// Returns `true` if the iteration was stopped by the cancellation flag.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    /// Consumes the adapter and returns the source iterator, which can be used to resume the iteration.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<'a, I, T, V> Iterator for Cancellable<'a, I, T>
+where
+    I: Iterator<Item = (T, V)>,
+    T: Clone,
+{
+    type Item = (T, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cancelled = self.flag.load(Ordering::Relaxed);
+        if self.cancelled {
+            return None;
+        }
+        let (i, v) = self.iter.next()?;
+        self.last_index = Some(i.clone());
+        Some((i, v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the flag may be set at any time
+        (0, self.iter.size_hint().1)
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2025 Redglyph
+//
+
+use core::fmt::{Debug, Formatter};
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// Creates an iterator which chains several sources with one continuous index, yielding
+/// `((i, source_no), val)`, where `i` is the global index of type `T`, `source_no` is the
+/// position of the source which produced `val`, and `val` is the value itself.
+///
+/// The global index starts at 0 and increments by 1. Empty sources produce no item, but they still
+/// count in the source number.
+///
+/// # Overflow Behavior
+///
+/// The function does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+/// and the number of items generated by the sources.
+///
+/// # Examples
+///
+/// ```
+/// use iter_index::chain_indexed;
+///
+/// let a = vec!["a", "b"];
+/// let b = vec![];
+/// let c = vec!["c"];
+/// let result = chain_indexed::<u32, _>([a, b, c]).collect::<Vec<_>>();
+///
+/// assert_eq!(result, vec![((0, 0), "a"), ((1, 0), "b"), ((2, 2), "c")]);
+/// ```
+pub fn chain_indexed<T, S>(sources: S) -> ChainIndexer<S::IntoIter, T>
+where
+    S: IntoIterator,
+    S::Item: IntoIterator,
+    u8: Into<T>,
+{
+    ChainIndexer::new(sources.into_iter(), 0.into(), 1.into())
+}
+
+/// An iterator that yields the continuous index and the source number, and the iteration item,
+/// of a sequence of sources.
+///
+/// This `struct` is created by the [`chain_indexed`] function.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChainIndexer<S, T>
+where
+    S: Iterator,
+    S::Item: IntoIterator,
+{
+    sources: S,
+    current: Option<<S::Item as IntoIterator>::IntoIter>,
+    source_no: usize,
+    counter: T,
+    step: T,
+}
+
+impl<S, T> ChainIndexer<S, T>
+where
+    S: Iterator,
+    S::Item: IntoIterator,
+{
+    pub fn new(sources: S, start: T, step: T) -> ChainIndexer<S, T> {
+        ChainIndexer { sources, current: None, source_no: 0, counter: start, step }
+    }
+}
+
+impl<S, T> Clone for ChainIndexer<S, T>
+where
+    S: Iterator + Clone,
+    S::Item: IntoIterator,
+    <S::Item as IntoIterator>::IntoIter: Clone,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        ChainIndexer {
+            sources: self.sources.clone(),
+            current: self.current.clone(),
+            source_no: self.source_no,
+            counter: self.counter.clone(),
+            step: self.step.clone(),
+        }
+    }
+}
+
+impl<S, T> Debug for ChainIndexer<S, T>
+where
+    S: Iterator + Debug,
+    S::Item: IntoIterator,
+    <S::Item as IntoIterator>::IntoIter: Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ChainIndexer")
+            .field("sources", &self.sources)
+            .field("current", &self.current)
+            .field("source_no", &self.source_no)
+            .field("counter", &self.counter)
+            .field("step", &self.step)
+            .finish()
+    }
+}
+
+impl<S, T> Iterator for ChainIndexer<S, T>
+where
+    S: Iterator,
+    S::Item: IntoIterator,
+    T: IndexValue,
+{
+    type Item = ((T, usize), <S::Item as IntoIterator>::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.current.as_mut().and_then(|it| it.next()) {
+                let result = Some(((self.counter.clone(), self.source_no - 1), item));
+                self.counter.add_assign_ref(&self.step);
+                return result;
+            }
+            self.current = Some(self.sources.next()?.into_iter());
+            self.source_no += 1;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.current.as_ref().map_or((0, Some(0)), |it| it.size_hint());
+        match self.sources.size_hint() {
+            (0, Some(0)) => (lo, hi),
+            _ => (lo, None),
+        }
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2025 Redglyph
+//
+
+use core::fmt::{Display, Formatter};
+use core::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// The error returned by the fallible index operations, like [`Indexer::try_nth`](crate::Indexer::try_nth).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IndexError {
+    /// A number of items couldn't be converted to the index type.
+    ConversionFailed,
+    /// The index calculation overflowed the index type.
+    Overflow,
+}
+
+impl Display for IndexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            IndexError::ConversionFailed => write!(f, "the number of items can't be converted to the index type"),
+            IndexError::Overflow => write!(f, "the index overflowed the index type"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndexError {}
+
+//------------------------------------------------------------------------------
+
+/// The checked arithmetic operations of an index type, which return `None` instead of overflowing.
+///
+/// The trait is implemented for the primitive integer types, for `num_rational::Ratio` with the
+/// `rational` feature, and for `num_bigint::BigUint` and `num_bigint::BigInt` with the `bigint` feature.
+pub trait CheckedIndex: Sized {
+    /// Returns `self + rhs`, or `None` if the result overflows.
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+
+    /// Returns `self * rhs`, or `None` if the result overflows.
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_index {
+    ($($t:ty),*) => {$(
+        impl CheckedIndex for $t {
+            #[inline]
+            fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *rhs)
+            }
+
+            #[inline]
+            fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_mul(*self, *rhs)
+            }
+        }
+    )*};
+}
+
+impl_checked_index!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Returns `counter + n * step`, or `None` if the result overflows. The steps are added by powers of two,
+/// like `add_steps`, so the calculation only fails if the result overflows.
+pub(crate) fn checked_add_steps<T: CheckedIndex + Clone>(counter: &T, step: &T, mut n: usize) -> Option<T> {
+    let mut i = counter.clone();
+    let mut power = step.clone();
+    while n > 0 {
+        if n & 1 != 0 {
+            i = i.checked_add(&power)?;
+        }
+        n >>= 1;
+        if n == 1 {
+            // the last power is added twice rather than calculated, since it may not fit in T
+            i = i.checked_add(&power)?.checked_add(&power)?;
+            break;
+        }
+        if n > 0 {
+            power = power.checked_add(&power)?;
+        }
+    }
+    Some(i)
+}
+
+#[cfg(feature = "bigint")]
+macro_rules! impl_checked_index_big {
+    ($($t:ty),*) => {$(
+        impl CheckedIndex for $t {
+            #[inline]
+            fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                num_traits::CheckedAdd::checked_add(self, rhs)
+            }
+
+            #[inline]
+            fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                num_traits::CheckedMul::checked_mul(self, rhs)
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "bigint")]
+impl_checked_index_big!(num_bigint::BigUint, num_bigint::BigInt);
+
+#[cfg(feature = "rational")]
+impl<T> CheckedIndex for num_rational::Ratio<T>
+where
+    num_rational::Ratio<T>: num_traits::CheckedAdd + num_traits::CheckedMul,
+{
+    #[inline]
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        num_traits::CheckedAdd::checked_add(self, rhs)
+    }
+
+    #[inline]
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        num_traits::CheckedMul::checked_mul(self, rhs)
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the current count, or `None` once it has overflowed the index type,
+/// and the iteration item.
+///
+/// This `struct` is created by the [`index_checked`](crate::IndexerIterator::index_checked)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CheckedIndexer<I, T> {
+    iter: I,
+    counter: Option<T>,
+    step: T,
+}
+
+impl<I, T> CheckedIndexer<I, T> {
+    pub fn new(iter: I, start: T, step: T) -> CheckedIndexer<I, T> {
+        CheckedIndexer { iter, counter: Some(start), step }
+    }
+}
+
+impl<I, T> Iterator for CheckedIndexer<I, T>
+where
+    I: Iterator,
+    T: CheckedIndex,
+{
+    type Item = (Option<T>, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let next = self.counter.as_ref().and_then(|i| i.checked_add(&self.step));
+        Some((core::mem::replace(&mut self.counter, next), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for CheckedIndexer<I, T>
+where
+    I: ExactSizeIterator,
+    T: CheckedIndex,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for CheckedIndexer<I, T>
+where
+    I: FusedIterator,
+    T: CheckedIndex,
+{}
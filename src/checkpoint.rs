@@ -0,0 +1,123 @@
+// Copyright 2025 Redglyph
+//
+
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{self, ErrorKind, Write};
+use std::path::Path;
+use std::str::FromStr;
+use crate::{IndexValue, Indexer};
+
+//------------------------------------------------------------------------------
+
+/// The state of a [`Checkpointed`] indexed iterator, which can be saved to a file and loaded back.
+///
+/// The state is stored as text, with one value per line: the start index, the step, and the number of
+/// items consumed from the source iterator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Checkpoint<T> {
+    pub start: T,
+    pub step: T,
+    pub consumed: u64,
+}
+
+impl<T: Display> Checkpoint<T> {
+    /// Saves the state to the file `path`.
+    ///
+    /// The state is first written to a temporary file, whose name is `path` followed by `.tmp`, which
+    /// then replaces `path`, so that the file is never left half-written if the process is interrupted.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        let mut file = File::create(&tmp_path)?;
+        write!(file, "{}\n{}\n{}\n", self.start, self.step, self.consumed)?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, path)
+    }
+}
+
+impl<T: FromStr> Checkpoint<T> {
+    /// Loads a state saved by [`Checkpoint::save`] from the file `path`.
+    ///
+    /// The method returns an error of kind [`ErrorKind::InvalidData`] if the content of the file
+    /// can't be parsed.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Checkpoint<T>> {
+        let text = fs::read_to_string(path)?;
+        let invalid = || io::Error::new(ErrorKind::InvalidData, "invalid checkpoint file");
+        let mut lines = text.lines();
+        let start = lines.next().and_then(|s| s.parse().ok()).ok_or_else(invalid)?;
+        let step = lines.next().and_then(|s| s.parse().ok()).ok_or_else(invalid)?;
+        let consumed = lines.next().and_then(|s| s.parse().ok()).ok_or_else(invalid)?;
+        Ok(Checkpoint { start, step, consumed })
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the current count, with the generic type, and the iteration item, and
+/// whose state can be saved to a file with [`Checkpointed::checkpoint_to`].
+///
+/// This `struct` is created by the [`resume_from`](crate::IndexerIterator::resume_from)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Checkpointed<I, T> {
+    indexer: Indexer<I, T>,
+    start: T,
+    consumed: u64,
+}
+
+impl<I, T> Checkpointed<I, T>
+where
+    I: Iterator,
+    T: IndexValue,
+{
+    /// Creates an indexed iterator starting from `checkpoint`: the first `checkpoint.consumed` items
+    /// of `iter` are skipped, and the iteration continues with the index they would have led to.
+    pub fn new(iter: I, checkpoint: Checkpoint<T>) -> Checkpointed<I, T> {
+        let Checkpoint { start, step, consumed } = checkpoint;
+        let mut result = Checkpointed { indexer: Indexer::new(iter, start.clone(), step), start, consumed: 0 };
+        while result.consumed < consumed && result.next().is_some() {}
+        result
+    }
+
+    /// Returns the current state of the iterator.
+    pub fn checkpoint(&self) -> Checkpoint<T> {
+        Checkpoint { start: self.start.clone(), step: self.indexer.step.clone(), consumed: self.consumed }
+    }
+
+    /// Saves the current state of the iterator to the file `path`; see [`Checkpoint::save`].
+    pub fn checkpoint_to<P: AsRef<Path>>(&self, path: P) -> io::Result<()> where T: Display {
+        self.checkpoint().save(path)
+    }
+}
+
+impl<I, T> Iterator for Checkpointed<I, T>
+where
+    I: Iterator,
+    T: IndexValue,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.indexer.next()?;
+        self.consumed += 1;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexer.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for Checkpointed<I, T>
+where
+    I: ExactSizeIterator,
+    T: IndexValue,
+{
+    fn len(&self) -> usize {
+        self.indexer.len()
+    }
+}
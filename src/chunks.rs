@@ -0,0 +1,68 @@
+// Copyright 2025 Redglyph
+//
+
+use std::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that groups the indexed items of the source iterator in chunks of `N` items, and
+/// yields the result of a closure called on the array of indices and the array of items of each chunk.
+///
+/// This `struct` is created by the [`map_indexed_chunks`](crate::IndexerIterator::map_indexed_chunks)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IndexedChunks<I, T, V, F, const N: usize> {
+    iter: I,
+    indices: Vec<T>,
+    items: Vec<V>,
+    f: F,
+}
+
+impl<I, T, V, F, const N: usize> IndexedChunks<I, T, V, F, N> {
+    pub fn new(iter: I, f: F) -> IndexedChunks<I, T, V, F, N> {
+        assert!(N != 0, "N must be greater than 0");
+        IndexedChunks { iter, indices: Vec::with_capacity(N), items: Vec::with_capacity(N), f }
+    }
+
+    /// Returns the indices and the items of the last, incomplete chunk, once the iteration is over.
+    /// Both slices are shorter than `N`.
+    pub fn remainder(&self) -> (&[T], &[V]) {
+        (&self.indices, &self.items)
+    }
+}
+
+impl<I, T, V, R, F, const N: usize> Iterator for IndexedChunks<I, T, V, F, N>
+where
+    I: Iterator<Item = (T, V)>,
+    F: FnMut(&[T; N], &[V; N]) -> R,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.indices.len() < N {
+            let (i, v) = self.iter.next()?;
+            self.indices.push(i);
+            self.items.push(v);
+        }
+        // the buffers are reused for the next chunk, so there's no allocation after the first one
+        let indices = <&[T; N]>::try_from(self.indices.as_slice()).ok()?;
+        let items = <&[V; N]>::try_from(self.items.as_slice()).ok()?;
+        let result = (self.f)(indices, items);
+        self.indices.clear();
+        self.items.clear();
+        Some(result)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (lo / N, hi.map(|hi| hi / N))
+    }
+}
+
+impl<I, T, V, R, F, const N: usize> FusedIterator for IndexedChunks<I, T, V, F, N>
+where
+    I: FusedIterator<Item = (T, V)>,
+    F: FnMut(&[T; N], &[V; N]) -> R,
+{}
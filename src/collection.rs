@@ -0,0 +1,80 @@
+// Copyright 2025 Redglyph
+//
+
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use crate::Indexer;
+
+//------------------------------------------------------------------------------
+
+/// An extension trait which provides the indexing methods of [`IndexerIterator`](crate::IndexerIterator)
+/// directly on collections, without calling `iter()` or `into_iter()` first.
+///
+/// The trait is implemented for the collections of the standard library, arrays and slices, and
+/// references to them; `vec.index()` consumes the vector like `vec.into_iter()` would, and `(&vec).index()`
+/// borrows it like `vec.iter()` would. It can be implemented for other collections with an empty `impl`
+/// block.
+///
+/// It's not implemented for all the `IntoIterator` types, because the iterators would then have both
+/// this trait and [`IndexerIterator`](crate::IndexerIterator), and the method calls would be ambiguous
+/// when both traits are in scope.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use iter_index::IndexedCollection;
+///
+/// let items = vec!["a", "b", "c"];
+/// let result = (&items).index_start::<u8>(1).collect::<Vec<_>>();
+/// assert_eq!(result, vec![(1, &"a"), (2, &"b"), (3, &"c")]);
+///
+/// let map = BTreeMap::from([("x", 10), ("y", 20)]);
+/// let result = map.index::<u32>().collect::<Vec<_>>();
+/// assert_eq!(result, vec![(0, ("x", 10)), (1, ("y", 20))]);
+/// ```
+pub trait IndexedCollection: IntoIterator + Sized {
+    /// Creates an iterator over the collection which gives an index of each value as well as the value
+    /// itself; see [`IndexerIterator::index`](crate::IndexerIterator::index).
+    fn index<T>(self) -> Indexer<Self::IntoIter, T> where u8: Into<T> {
+        Indexer::new(self.into_iter(), 0.into(), 1.into())
+    }
+
+    /// Creates an iterator over the collection which gives an index of each value as well as the value
+    /// itself; see [`IndexerIterator::index_start`](crate::IndexerIterator::index_start).
+    fn index_start<T>(self, start: T) -> Indexer<Self::IntoIter, T> where u8: Into<T> {
+        Indexer::new(self.into_iter(), start, 1.into())
+    }
+
+    /// Creates an iterator over the collection which gives an index of each value as well as the value
+    /// itself; see [`IndexerIterator::index_step`](crate::IndexerIterator::index_step).
+    fn index_step<T>(self, start: T, step: T) -> Indexer<Self::IntoIter, T> {
+        Indexer::new(self.into_iter(), start, step)
+    }
+}
+
+impl<V> IndexedCollection for Vec<V> {}
+impl<V> IndexedCollection for &Vec<V> {}
+impl<V> IndexedCollection for &mut Vec<V> {}
+impl<V, const N: usize> IndexedCollection for [V; N] {}
+impl<V, const N: usize> IndexedCollection for &[V; N] {}
+impl<V, const N: usize> IndexedCollection for &mut [V; N] {}
+impl<V> IndexedCollection for &[V] {}
+impl<V> IndexedCollection for &mut [V] {}
+impl<V> IndexedCollection for VecDeque<V> {}
+impl<V> IndexedCollection for &VecDeque<V> {}
+impl<V> IndexedCollection for &mut VecDeque<V> {}
+impl<V> IndexedCollection for LinkedList<V> {}
+impl<V> IndexedCollection for &LinkedList<V> {}
+impl<V> IndexedCollection for &mut LinkedList<V> {}
+impl<V> IndexedCollection for BinaryHeap<V> {}
+impl<V> IndexedCollection for &BinaryHeap<V> {}
+impl<K, V, S> IndexedCollection for HashMap<K, V, S> {}
+impl<K, V, S> IndexedCollection for &HashMap<K, V, S> {}
+impl<K, V, S> IndexedCollection for &mut HashMap<K, V, S> {}
+impl<K, V> IndexedCollection for BTreeMap<K, V> {}
+impl<K, V> IndexedCollection for &BTreeMap<K, V> {}
+impl<K, V> IndexedCollection for &mut BTreeMap<K, V> {}
+impl<V, S> IndexedCollection for HashSet<V, S> {}
+impl<V, S> IndexedCollection for &HashSet<V, S> {}
+impl<V> IndexedCollection for BTreeSet<V> {}
+impl<V> IndexedCollection for &BTreeSet<V> {}
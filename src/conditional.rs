@@ -0,0 +1,76 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields an index for the items matching a predicate, `None` for the other
+/// items, and the iteration item.
+///
+/// This `struct` is created by the [`index_if`](crate::IndexerIterator::index_if)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ConditionalIndexer<I, T, P> {
+    iter: I,
+    counter: T,
+    step: T,
+    predicate: P,
+}
+
+impl<I, T, P> ConditionalIndexer<I, T, P> {
+    pub fn new(iter: I, start: T, step: T, predicate: P) -> ConditionalIndexer<I, T, P> {
+        ConditionalIndexer { iter, counter: start, step, predicate }
+    }
+
+    /// Returns the index that will be given to the next matching item, which is also the
+    /// number of matching items so far when the index starts at 0 and increments by 1.
+    pub fn next_index(&self) -> &T {
+        &self.counter
+    }
+}
+
+impl<I, T, P> Iterator for ConditionalIndexer<I, T, P>
+where
+    I: Iterator,
+    T: IndexValue,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = (Option<T>, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        if (self.predicate)(&item) {
+            let result = Some((Some(self.counter.clone()), item));
+            self.counter.add_assign_ref(&self.step);
+            result
+        } else {
+            Some((None, item))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, P> ExactSizeIterator for ConditionalIndexer<I, T, P>
+where
+    I: ExactSizeIterator,
+    T: IndexValue,
+    P: FnMut(&I::Item) -> bool,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T, P> FusedIterator for ConditionalIndexer<I, T, P>
+where
+    I: FusedIterator,
+    T: IndexValue,
+    P: FnMut(&I::Item) -> bool,
+{}
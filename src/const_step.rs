@@ -0,0 +1,95 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use crate::IndexValue;
+use crate::value::add_steps;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the current count, incremented by the constant `STEP`, and the iteration item.
+///
+/// This `struct` is created by the [`index_const_step`](crate::IndexerIterator::index_const_step)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ConstStepIndexer<I, T, const STEP: usize> {
+    iter: I,
+    counter: T,
+    step: T,
+}
+
+impl<I, T: TryFrom<usize>, const STEP: usize> ConstStepIndexer<I, T, STEP> {
+    pub fn new(iter: I, start: T) -> ConstStepIndexer<I, T, STEP> {
+        let step = T::try_from(STEP).unwrap_or_else(|_| panic!("Cannot convert STEP = {STEP} into {}", core::any::type_name::<T>()));
+        ConstStepIndexer { iter, counter: start, step }
+    }
+}
+
+impl<I, T, const STEP: usize> ConstStepIndexer<I, T, STEP>
+where
+    T: IndexValue + TryFrom<usize>,
+{
+    /// Returns the index `n` steps after the next one. `n * STEP` is a multiplication by a constant;
+    /// if it doesn't fit in `T`, the steps are added like in [`Indexer`](crate::Indexer).
+    fn index(&self, n: usize) -> T {
+        match n.checked_mul(STEP).map(T::try_from) {
+            Some(Ok(offset)) => self.counter.add_ref(&offset),
+            _ => add_steps(&self.counter, &self.step, n),
+        }
+    }
+}
+
+impl<I, T, const STEP: usize> Iterator for ConstStepIndexer<I, T, STEP>
+where
+    I: Iterator,
+    T: IndexValue + TryFrom<usize>,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let next = self.counter.add_ref(&self.step);
+        Some((core::mem::replace(&mut self.counter, next), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let item = self.iter.nth(n)?;
+        let i = self.index(n);
+        self.counter = i.add_ref(&self.step);
+        Some((i, item))
+    }
+}
+
+impl<I, T, const STEP: usize> DoubleEndedIterator for ConstStepIndexer<I, T, STEP>
+where
+    I: ExactSizeIterator + DoubleEndedIterator,
+    T: IndexValue + TryFrom<usize>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        Some((self.index(self.iter.len()), item))
+    }
+}
+
+impl<I, T, const STEP: usize> ExactSizeIterator for ConstStepIndexer<I, T, STEP>
+where
+    I: ExactSizeIterator,
+    T: IndexValue + TryFrom<usize>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T, const STEP: usize> FusedIterator for ConstStepIndexer<I, T, STEP>
+where
+    I: FusedIterator,
+    T: IndexValue + TryFrom<usize>,
+{}
@@ -0,0 +1,76 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the indexed items of the source iterator along with the previous and
+/// the next items.
+///
+/// This `struct` is created by the [`with_context`](crate::IndexerIterator::with_context)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Context<I, T, V> {
+    iter: I,
+    prev: Option<V>,
+    // `None` until the first item has been pulled
+    ahead: Option<Option<(T, V)>>,
+}
+
+impl<I, T, V> Context<I, T, V> {
+    pub fn new(iter: I) -> Context<I, T, V> {
+        Context { iter, prev: None, ahead: None }
+    }
+}
+
+impl<I, T, V> Iterator for Context<I, T, V>
+where
+    I: Iterator<Item = (T, V)>,
+    V: Clone,
+{
+    type Item = (T, Option<V>, V, Option<V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pulled = match self.ahead.take() {
+            Some(ahead) => ahead,
+            None => self.iter.next(),
+        };
+        let (i, current) = match pulled {
+            Some(item) => item,
+            None => {
+                // the end is sticky, so the source isn't polled again
+                self.ahead = Some(None);
+                return None;
+            }
+        };
+        let ahead = self.ahead.insert(self.iter.next());
+        let next = ahead.as_ref().map(|(_, v)| v.clone());
+        let prev = self.prev.replace(current.clone());
+        Some((i, prev, current, next))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = match self.ahead {
+            None => return self.iter.size_hint(),
+            Some(None) => return (0, Some(0)),
+            Some(Some(_)) => 1,
+        };
+        let (lo, hi) = self.iter.size_hint();
+        (lo.saturating_add(n), hi.and_then(|hi| hi.checked_add(n)))
+    }
+}
+
+impl<I, T, V> ExactSizeIterator for Context<I, T, V>
+where
+    I: ExactSizeIterator<Item = (T, V)>,
+    V: Clone,
+{}
+
+impl<I, T, V> FusedIterator for Context<I, T, V>
+where
+    I: Iterator<Item = (T, V)>,
+    V: Clone,
+{}
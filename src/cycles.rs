@@ -0,0 +1,69 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use crate::value::to_index;
+
+//------------------------------------------------------------------------------
+
+/// An iterator over repeated passes of the same values that yields the cycle number, the position
+/// in the cycle, and the item itself.
+///
+/// This `struct` is created by the [`index_cycles`](crate::IndexerIterator::index_cycles)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CycleIndexer<I, T> {
+    iter: I,
+    cycle_len: usize,
+    cycle: usize,
+    position: usize,
+    _marker: core::marker::PhantomData<fn() -> T>,
+}
+
+impl<I, T> CycleIndexer<I, T> {
+    pub fn new(iter: I, cycle_len: usize) -> CycleIndexer<I, T> {
+        assert!(cycle_len > 0, "the cycle length must be greater than 0");
+        CycleIndexer { iter, cycle_len, cycle: 0, position: 0, _marker: core::marker::PhantomData }
+    }
+}
+
+impl<I, T> Iterator for CycleIndexer<I, T>
+where
+    I: Iterator,
+    T: TryFrom<usize>,
+{
+    type Item = (T, T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let result = Some((to_index(self.cycle), to_index(self.position), item));
+        self.position += 1;
+        if self.position == self.cycle_len {
+            self.position = 0;
+            self.cycle += 1;
+        }
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for CycleIndexer<I, T>
+where
+    I: ExactSizeIterator,
+    T: TryFrom<usize>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for CycleIndexer<I, T>
+where
+    I: FusedIterator,
+    T: TryFrom<usize>,
+{}
@@ -0,0 +1,80 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use chrono::{NaiveDate, TimeDelta};
+
+//------------------------------------------------------------------------------
+
+const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+
+/// An iterator that yields a calendar date and the iteration item.
+///
+/// This `struct` is created by the [`index_dates`](crate::IndexerIterator::index_dates)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DateIndexer<I> {
+    iter: I,
+    start: NaiveDate,
+    step_nanos: i128,
+    // number of items taken from the front
+    count: usize,
+}
+
+impl<I> DateIndexer<I> {
+    pub fn new(iter: I, start: NaiveDate, step: TimeDelta) -> DateIndexer<I> {
+        let step_nanos = step.num_seconds() as i128 * 1_000_000_000 + step.subsec_nanos() as i128;
+        DateIndexer { iter, start, step_nanos, count: 0 }
+    }
+
+    /// Returns `start + n * step`. The date is calculated from the start each time, so a step that
+    /// isn't a whole number of days doesn't accumulate rounding errors.
+    fn date(&self, n: usize) -> NaiveDate {
+        self.step_nanos.checked_mul(n as i128)
+            .and_then(|nanos| i64::try_from(nanos / NANOS_PER_DAY).ok())
+            .and_then(TimeDelta::try_days)
+            .and_then(|days| self.start.checked_add_signed(days))
+            .expect("the index overflowed NaiveDate")
+    }
+}
+
+impl<I: Iterator> Iterator for DateIndexer<I> {
+    type Item = (NaiveDate, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.count += 1;
+        Some((self.date(self.count - 1), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let item = self.iter.nth(n)?;
+        self.count += n + 1;
+        Some((self.date(self.count - 1), item))
+    }
+}
+
+impl<I> DoubleEndedIterator for DateIndexer<I>
+where
+    I: ExactSizeIterator + DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        Some((self.date(self.count + self.iter.len()), item))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for DateIndexer<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for DateIndexer<I> {}
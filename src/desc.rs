@@ -0,0 +1,68 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use core::ops::SubAssign;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields a decreasing count and the iteration item.
+///
+/// This `struct` is created by the [`index_desc`](crate::IndexerIterator::index_desc) and
+/// [`index_desc_step`](crate::IndexerIterator::index_desc_step) methods on
+/// [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DescIndexer<I, T> {
+    iter: I,
+    counter: T,
+    step: T,
+    first: bool,
+}
+
+impl<I, T> DescIndexer<I, T> {
+    pub fn new(iter: I, start: T, step: T) -> DescIndexer<I, T> {
+        DescIndexer { iter, counter: start, step, first: true }
+    }
+}
+
+impl<I, T> Iterator for DescIndexer<I, T>
+where
+    I: Iterator,
+    T: Clone + for<'a> SubAssign<&'a T>,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        // the counter is only decremented when another item comes, so an unsigned
+        // index can count down to 0 without underflowing
+        if self.first {
+            self.first = false;
+        } else {
+            self.counter -= &self.step;
+        }
+        Some((self.counter.clone(), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for DescIndexer<I, T>
+where
+    I: ExactSizeIterator,
+    T: Clone + for<'a> SubAssign<&'a T>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for DescIndexer<I, T>
+where
+    I: FusedIterator,
+    T: Clone + for<'a> SubAssign<&'a T>,
+{}
@@ -0,0 +1,75 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use core::time::Duration;
+use crate::value::add_steps_with;
+
+//------------------------------------------------------------------------------
+
+const OVERFLOW: &str = "the index overflowed Duration";
+
+/// Returns `start + n * step`, with the steps added by powers of two so that `n` doesn't need to
+/// fit in a `u32`.
+fn add_steps(start: Duration, step: Duration, n: usize) -> Duration {
+    add_steps_with(start, step, n, |i, s| i.checked_add(*s).expect(OVERFLOW), |s| s.checked_add(*s).expect(OVERFLOW))
+}
+
+/// An iterator that yields a [`Duration`] index, like a timestamp, and the iteration item.
+///
+/// This `struct` is created by the [`index_duration`](crate::IndexerIterator::index_duration)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DurationIndexer<I> {
+    iter: I,
+    counter: Duration,
+    step: Duration,
+}
+
+impl<I> DurationIndexer<I> {
+    pub fn new(iter: I, start: Duration, step: Duration) -> DurationIndexer<I> {
+        DurationIndexer { iter, counter: start, step }
+    }
+}
+
+impl<I: Iterator> Iterator for DurationIndexer<I> {
+    type Item = (Duration, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let next = self.counter.checked_add(self.step).expect(OVERFLOW);
+        Some((core::mem::replace(&mut self.counter, next), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let item = self.iter.nth(n)?;
+        let i = add_steps(self.counter, self.step, n);
+        self.counter = i.checked_add(self.step).expect(OVERFLOW);
+        Some((i, item))
+    }
+}
+
+impl<I> DoubleEndedIterator for DurationIndexer<I>
+where
+    I: ExactSizeIterator + DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        Some((add_steps(self.counter, self.step, self.iter.len()), item))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for DurationIndexer<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for DurationIndexer<I> {}
@@ -0,0 +1,67 @@
+// Copyright 2025 Redglyph
+//
+
+use std::iter::FusedIterator;
+use std::time::{Duration, Instant};
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the current count and the time elapsed since the previous item, and
+/// the iteration item.
+///
+/// This `struct` is created by the [`index_elapsed`](crate::IndexerIterator::index_elapsed)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ElapsedIndexer<I, T> {
+    iter: I,
+    counter: T,
+    step: T,
+    last: Option<Instant>,
+}
+
+impl<I, T> ElapsedIndexer<I, T> {
+    pub fn new(iter: I, start: T, step: T) -> ElapsedIndexer<I, T> {
+        ElapsedIndexer { iter, counter: start, step, last: None }
+    }
+}
+
+impl<I, T> Iterator for ElapsedIndexer<I, T>
+where
+    I: Iterator,
+    T: IndexValue,
+{
+    type Item = ((T, Duration), I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let last = *self.last.get_or_insert_with(Instant::now);
+        let item = self.iter.next()?;
+        let now = Instant::now();
+        self.last = Some(now);
+        let result = Some(((self.counter.clone(), now.duration_since(last)), item));
+        self.counter.add_assign_ref(&self.step);
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for ElapsedIndexer<I, T>
+where
+    I: ExactSizeIterator,
+    T: IndexValue,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for ElapsedIndexer<I, T>
+where
+    I: FusedIterator,
+    T: IndexValue,
+{}
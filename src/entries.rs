@@ -0,0 +1,67 @@
+// Copyright 2025 Redglyph
+//
+
+use crate::{IndexValue, Indexer};
+
+//------------------------------------------------------------------------------
+
+/// An iterator over key-value pairs that yields the current count, the key and the value as
+/// a flat triple.
+///
+/// This `struct` is created by the [`index_entries`](crate::IndexerIterator::index_entries)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct EntryIndexer<I, T> {
+    indexer: Indexer<I, T>,
+}
+
+impl<I, T> EntryIndexer<I, T> {
+    pub fn new(iter: I, start: T, step: T) -> EntryIndexer<I, T> {
+        EntryIndexer { indexer: Indexer::new(iter, start, step) }
+    }
+}
+
+impl<I, T, K, V> Iterator for EntryIndexer<I, T>
+where
+    I: Iterator<Item = (K, V)>,
+    T: IndexValue,
+{
+    type Item = (T, K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.indexer.next().map(|(i, (k, v))| (i, k, v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexer.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.indexer.nth(n).map(|(i, (k, v))| (i, k, v))
+    }
+}
+
+impl<I, T, K, V> DoubleEndedIterator for EntryIndexer<I, T>
+where
+    I: ExactSizeIterator<Item = (K, V)> + DoubleEndedIterator,
+    T: IndexValue,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.indexer.next_back().map(|(i, (k, v))| (i, k, v))
+    }
+}
+
+impl<I, T, K, V> ExactSizeIterator for EntryIndexer<I, T>
+where
+    I: ExactSizeIterator<Item = (K, V)>,
+    T: IndexValue,
+{
+    fn len(&self) -> usize {
+        self.indexer.len()
+    }
+}
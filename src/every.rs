@@ -0,0 +1,66 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that passes the indexed items of the source iterator through, while calling a
+/// closure every `n` items.
+///
+/// This `struct` is created by the [`on_every`](crate::IndexerIterator::on_every)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OnEvery<I, F> {
+    iter: I,
+    n: usize,
+    countdown: usize,
+    f: F,
+}
+
+impl<I, F> OnEvery<I, F> {
+    pub fn new(iter: I, n: usize, f: F) -> OnEvery<I, F> {
+        assert!(n != 0, "n must be greater than 0");
+        OnEvery { iter, n, countdown: n, f }
+    }
+}
+
+impl<I, T, V, F> Iterator for OnEvery<I, F>
+where
+    I: Iterator<Item = (T, V)>,
+    F: FnMut(&T, &V),
+{
+    type Item = (T, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.countdown -= 1;
+        if self.countdown == 0 {
+            (self.f)(&item.0, &item.1);
+            self.countdown = self.n;
+        }
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, V, F> ExactSizeIterator for OnEvery<I, F>
+where
+    I: ExactSizeIterator<Item = (T, V)>,
+    F: FnMut(&T, &V),
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T, V, F> FusedIterator for OnEvery<I, F>
+where
+    I: FusedIterator<Item = (T, V)>,
+    F: FnMut(&T, &V),
+{}
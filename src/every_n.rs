@@ -0,0 +1,68 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields an index incremented once every `n` items, and the iteration item.
+///
+/// This `struct` is created by the [`index_every`](crate::IndexerIterator::index_every)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct EveryIndexer<I, T> {
+    iter: I,
+    n: usize,
+    countdown: usize,
+    counter: T,
+    step: T,
+}
+
+impl<I, T> EveryIndexer<I, T> {
+    pub fn new(iter: I, n: usize, start: T, step: T) -> EveryIndexer<I, T> {
+        assert!(n != 0, "n must be greater than 0");
+        EveryIndexer { iter, n, countdown: n, counter: start, step }
+    }
+}
+
+impl<I, T> Iterator for EveryIndexer<I, T>
+where
+    I: Iterator,
+    T: IndexValue,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        // the index only moves when an item of the next batch comes, so it can't overflow needlessly
+        if self.countdown == 0 {
+            self.counter.add_assign_ref(&self.step);
+            self.countdown = self.n;
+        }
+        self.countdown -= 1;
+        Some((self.counter.clone(), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for EveryIndexer<I, T>
+where
+    I: ExactSizeIterator,
+    T: IndexValue,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for EveryIndexer<I, T>
+where
+    I: FusedIterator,
+    T: IndexValue,
+{}
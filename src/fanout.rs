@@ -0,0 +1,68 @@
+// Copyright 2025 Redglyph
+//
+
+use crossbeam_channel::{bounded, Receiver, SendError, Sender};
+
+//------------------------------------------------------------------------------
+
+/// The receiving ends of the channels of a [`FanOut`].
+pub type Receivers<T, V> = Vec<Receiver<(T, V)>>;
+
+/// The type of shard function of a [`FanOut`] created by [`fan_out`](crate::IndexerIterator::fan_out),
+/// which distributes the items round-robin.
+pub type RoundRobin<T, V> = fn(&T, &V) -> usize;
+
+//------------------------------------------------------------------------------
+
+/// The sending side of a fan-out, which distributes the indexed items of the source iterator to
+/// several bounded channels.
+///
+/// This `struct` is created by the [`fan_out`](crate::IndexerIterator::fan_out) and
+/// [`fan_out_by`](crate::IndexerIterator::fan_out_by) methods on [`IndexerIterator`](crate::IndexerIterator).
+/// Nothing is sent until [`FanOut::run`] is called.
+#[derive(Debug)]
+#[must_use = "nothing is sent until run() is called"]
+pub struct FanOut<I: Iterator, F> {
+    iter: I,
+    senders: Vec<Sender<I::Item>>,
+    shard: Option<F>,
+}
+
+impl<I: Iterator, F> FanOut<I, F> {
+    pub fn new(iter: I, n: usize, capacity: usize, shard: Option<F>) -> (FanOut<I, F>, Vec<Receiver<I::Item>>) {
+        assert!(n != 0, "n must be greater than 0");
+        let (senders, receivers) = (0..n).map(|_| bounded(capacity)).unzip();
+        (FanOut { iter, senders, shard }, receivers)
+    }
+}
+
+impl<I, T, V, F> FanOut<I, F>
+where
+    I: Iterator<Item = (T, V)>,
+    F: FnMut(&T, &V) -> usize,
+{
+    /// Sends all the items of the source iterator to the channels, blocking when a channel is full, then
+    /// closes the channels.
+    ///
+    /// # Errors
+    ///
+    /// The method stops and returns the item that couldn't be sent if the receiver of its channel has
+    /// been dropped.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the shard function returns an index greater than or equal to the number of
+    /// channels.
+    pub fn run(mut self) -> Result<(), SendError<(T, V)>> {
+        let n = self.senders.len();
+        for (k, (i, v)) in self.iter.enumerate() {
+            let channel = match &mut self.shard {
+                Some(shard) => shard(&i, &v),
+                None => k % n,
+            };
+            assert!(channel < n, "shard index {channel} is out of range for {n} channels");
+            self.senders[channel].send((i, v))?;
+        }
+        Ok(())
+    }
+}
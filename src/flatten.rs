@@ -0,0 +1,111 @@
+// Copyright 2025 Redglyph
+//
+
+use core::fmt::{Debug, Formatter};
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that flattens an iterator of iterables, yielding the index of the sub-iterable
+/// and the index inside it, and the iteration item.
+///
+/// This `struct` is created by the [`flatten_indexed`](crate::IndexerIterator::flatten_indexed)
+/// and [`flat_map_indexed`](crate::IndexerIterator::flat_map_indexed) methods on
+/// [`IndexerIterator`](crate::IndexerIterator).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FlattenIndexer<I, T>
+where
+    I: Iterator,
+    I::Item: IntoIterator,
+{
+    iter: I,
+    current: Option<<I::Item as IntoIterator>::IntoIter>,
+    outer: Option<T>,
+    inner: T,
+    start: T,
+    step: T,
+}
+
+impl<I, T> FlattenIndexer<I, T>
+where
+    I: Iterator,
+    I::Item: IntoIterator,
+    T: Clone,
+{
+    pub fn new(iter: I, start: T, step: T) -> FlattenIndexer<I, T> {
+        FlattenIndexer { iter, current: None, outer: None, inner: start.clone(), start, step }
+    }
+}
+
+impl<I, T> Clone for FlattenIndexer<I, T>
+where
+    I: Iterator + Clone,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::IntoIter: Clone,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        FlattenIndexer {
+            iter: self.iter.clone(),
+            current: self.current.clone(),
+            outer: self.outer.clone(),
+            inner: self.inner.clone(),
+            start: self.start.clone(),
+            step: self.step.clone(),
+        }
+    }
+}
+
+impl<I, T> Debug for FlattenIndexer<I, T>
+where
+    I: Iterator + Debug,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::IntoIter: Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FlattenIndexer")
+            .field("iter", &self.iter)
+            .field("current", &self.current)
+            .field("outer", &self.outer)
+            .field("inner", &self.inner)
+            .field("start", &self.start)
+            .field("step", &self.step)
+            .finish()
+    }
+}
+
+impl<I, T> Iterator for FlattenIndexer<I, T>
+where
+    I: Iterator,
+    I::Item: IntoIterator,
+    T: IndexValue,
+{
+    type Item = ((T, T), <I::Item as IntoIterator>::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.current.as_mut().and_then(|it| it.next()) {
+                // `outer` is always set once `current` is
+                let outer = self.outer.clone().unwrap();
+                let result = Some(((outer, self.inner.clone()), item));
+                self.inner.add_assign_ref(&self.step);
+                return result;
+            }
+            self.current = Some(self.iter.next()?.into_iter());
+            match &mut self.outer {
+                Some(outer) => outer.add_assign_ref(&self.step),
+                None => self.outer = Some(self.start.clone()),
+            }
+            self.inner = self.start.clone();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.current.as_ref().map_or((0, Some(0)), |it| it.size_hint());
+        match self.iter.size_hint() {
+            (0, Some(0)) => (lo, hi),
+            _ => (lo, None),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2025 Redglyph
+//
+
+use std::iter::FusedIterator;
+use crate::{IndexValue, Indexer};
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the current count formatted as a string, and the iteration item.
+///
+/// This `struct` is created by the [`index_format`](crate::IndexerIterator::index_format)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FormatIndexer<I, T, F> {
+    indexer: Indexer<I, T>,
+    fmt: F,
+}
+
+impl<I, T, F> FormatIndexer<I, T, F> {
+    pub fn new(iter: I, start: T, step: T, fmt: F) -> FormatIndexer<I, T, F> {
+        FormatIndexer { indexer: Indexer::new(iter, start, step), fmt }
+    }
+}
+
+impl<I, T, F> Iterator for FormatIndexer<I, T, F>
+where
+    I: Iterator,
+    T: IndexValue,
+    F: FnMut(&T) -> String,
+{
+    type Item = (String, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, v) = self.indexer.next()?;
+        Some(((self.fmt)(&i), v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexer.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (i, v) = self.indexer.nth(n)?;
+        Some(((self.fmt)(&i), v))
+    }
+}
+
+impl<I, T, F> ExactSizeIterator for FormatIndexer<I, T, F>
+where
+    I: ExactSizeIterator,
+    T: IndexValue,
+    F: FnMut(&T) -> String,
+{
+    fn len(&self) -> usize {
+        self.indexer.len()
+    }
+}
+
+impl<I, T, F> FusedIterator for FormatIndexer<I, T, F>
+where
+    I: FusedIterator,
+    T: IndexValue,
+    F: FnMut(&T) -> String,
+{}
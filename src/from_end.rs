@@ -0,0 +1,79 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use crate::value::to_index;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the distance of each item from the end of the source iterator, and
+/// the iteration item.
+///
+/// This `struct` is created by the [`index_from_end`](crate::IndexerIterator::index_from_end)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FromEndIndexer<I, T> {
+    iter: I,
+    // number of items taken from the back
+    back: usize,
+    _marker: core::marker::PhantomData<fn() -> T>,
+}
+
+impl<I, T> FromEndIndexer<I, T> {
+    pub fn new(iter: I) -> FromEndIndexer<I, T> {
+        FromEndIndexer { iter, back: 0, _marker: core::marker::PhantomData }
+    }
+}
+
+impl<I, T> Iterator for FromEndIndexer<I, T>
+where
+    I: ExactSizeIterator,
+    T: TryFrom<usize>,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        Some((to_index(self.iter.len() + self.back), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let item = self.iter.nth(n)?;
+        Some((to_index(self.iter.len() + self.back), item))
+    }
+}
+
+impl<I, T> DoubleEndedIterator for FromEndIndexer<I, T>
+where
+    I: ExactSizeIterator + DoubleEndedIterator,
+    T: TryFrom<usize>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        self.back += 1;
+        Some((to_index(self.back - 1), item))
+    }
+}
+
+impl<I, T> ExactSizeIterator for FromEndIndexer<I, T>
+where
+    I: ExactSizeIterator,
+    T: TryFrom<usize>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for FromEndIndexer<I, T>
+where
+    I: ExactSizeIterator + FusedIterator,
+    T: TryFrom<usize>,
+{}
@@ -0,0 +1,122 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields an index generated by a closure and the iteration item.
+///
+/// This `struct` is created by the [`index_with`](crate::IndexerIterator::index_with)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IndexWith<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> IndexWith<I, F> {
+    pub fn new(iter: I, f: F) -> IndexWith<I, F> {
+        IndexWith { iter, f }
+    }
+}
+
+impl<I, T, F> Iterator for IndexWith<I, F>
+where
+    I: Iterator,
+    F: FnMut() -> T,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        Some(((self.f)(), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, F> ExactSizeIterator for IndexWith<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut() -> T,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T, F> FusedIterator for IndexWith<I, F>
+where
+    I: FusedIterator,
+    F: FnMut() -> T,
+{}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields an index computed by a closure from the previous index, and the
+/// iteration item.
+///
+/// This `struct` is created by the [`index_successors`](crate::IndexerIterator::index_successors)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IndexSuccessors<I, T, F> {
+    iter: I,
+    counter: T,
+    succ: F,
+    first: bool,
+}
+
+impl<I, T, F> IndexSuccessors<I, T, F> {
+    pub fn new(iter: I, first: T, succ: F) -> IndexSuccessors<I, T, F> {
+        IndexSuccessors { iter, counter: first, succ, first: true }
+    }
+}
+
+impl<I, T, F> Iterator for IndexSuccessors<I, T, F>
+where
+    I: Iterator,
+    T: Clone,
+    F: FnMut(&T) -> T,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        // the closure is only called when another item comes
+        if self.first {
+            self.first = false;
+        } else {
+            self.counter = (self.succ)(&self.counter);
+        }
+        Some((self.counter.clone(), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, F> ExactSizeIterator for IndexSuccessors<I, T, F>
+where
+    I: ExactSizeIterator,
+    T: Clone,
+    F: FnMut(&T) -> T,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T, F> FusedIterator for IndexSuccessors<I, T, F>
+where
+    I: FusedIterator,
+    T: Clone,
+    F: FnMut(&T) -> T,
+{}
@@ -0,0 +1,207 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use crate::value::to_index;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the `(row, col)` coordinates of each item of a flat row-major grid,
+/// and the item itself.
+///
+/// This `struct` is created by the [`index_2d`](crate::IndexerIterator::index_2d) and
+/// [`index_chunked`](crate::IndexerIterator::index_chunked) methods on
+/// [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Index2d<I, T> {
+    iter: I,
+    width: usize,
+    row: usize,
+    col: usize,
+    _marker: core::marker::PhantomData<fn() -> T>,
+}
+
+impl<I, T> Index2d<I, T> {
+    pub fn new(iter: I, width: usize) -> Index2d<I, T> {
+        assert!(width > 0, "the width must be greater than 0");
+        Index2d { iter, width, row: 0, col: 0, _marker: core::marker::PhantomData }
+    }
+}
+
+impl<I, T> Iterator for Index2d<I, T>
+where
+    I: Iterator,
+    T: TryFrom<usize>,
+{
+    type Item = ((T, T), I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let result = Some(((to_index(self.row), to_index(self.col)), item));
+        self.col += 1;
+        if self.col == self.width {
+            self.col = 0;
+            self.row += 1;
+        }
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for Index2d<I, T>
+where
+    I: ExactSizeIterator,
+    T: TryFrom<usize>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for Index2d<I, T>
+where
+    I: FusedIterator,
+    T: TryFrom<usize>,
+{}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the `(x, y, z)` coordinates of each item of a flat volume, where `x`
+/// varies the fastest, and the item itself.
+///
+/// This `struct` is created by the [`index_3d`](crate::IndexerIterator::index_3d)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Index3d<I, T> {
+    iter: I,
+    width: usize,
+    height: usize,
+    x: usize,
+    y: usize,
+    z: usize,
+    _marker: core::marker::PhantomData<fn() -> T>,
+}
+
+impl<I, T> Index3d<I, T> {
+    pub fn new(iter: I, width: usize, height: usize) -> Index3d<I, T> {
+        assert!(width > 0 && height > 0, "the width and the height must be greater than 0");
+        Index3d { iter, width, height, x: 0, y: 0, z: 0, _marker: core::marker::PhantomData }
+    }
+}
+
+impl<I, T> Iterator for Index3d<I, T>
+where
+    I: Iterator,
+    T: TryFrom<usize>,
+{
+    type Item = ((T, T, T), I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let result = Some(((to_index(self.x), to_index(self.y), to_index(self.z)), item));
+        self.x += 1;
+        if self.x == self.width {
+            self.x = 0;
+            self.y += 1;
+            if self.y == self.height {
+                self.y = 0;
+                self.z += 1;
+            }
+        }
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for Index3d<I, T>
+where
+    I: ExactSizeIterator,
+    T: TryFrom<usize>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for Index3d<I, T>
+where
+    I: FusedIterator,
+    T: TryFrom<usize>,
+{}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the coordinates of each item of a flat N-dimensional array in row-major
+/// order, where the last coordinate varies the fastest, and the item itself.
+///
+/// This `struct` is created by the [`index_nd`](crate::IndexerIterator::index_nd)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IndexNd<I, T, const N: usize> {
+    iter: I,
+    shape: [usize; N],
+    coords: [usize; N],
+    _marker: core::marker::PhantomData<fn() -> T>,
+}
+
+impl<I, T, const N: usize> IndexNd<I, T, N> {
+    pub fn new(iter: I, shape: [usize; N]) -> IndexNd<I, T, N> {
+        assert!(N > 0, "the shape must have at least one dimension");
+        assert!(shape.iter().all(|&n| n > 0), "the dimensions of the shape must be greater than 0");
+        IndexNd { iter, shape, coords: [0; N], _marker: core::marker::PhantomData }
+    }
+}
+
+impl<I, T, const N: usize> Iterator for IndexNd<I, T, N>
+where
+    I: Iterator,
+    T: TryFrom<usize>,
+{
+    type Item = ([T; N], I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let result = Some((self.coords.map(to_index), item));
+        // the first coordinate isn't wrapped, so it keeps counting past the shape
+        for axis in (0..N).rev() {
+            self.coords[axis] += 1;
+            if axis == 0 || self.coords[axis] < self.shape[axis] {
+                break;
+            }
+            self.coords[axis] = 0;
+        }
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, const N: usize> ExactSizeIterator for IndexNd<I, T, N>
+where
+    I: ExactSizeIterator,
+    T: TryFrom<usize>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T, const N: usize> FusedIterator for IndexNd<I, T, N>
+where
+    I: FusedIterator,
+    T: TryFrom<usize>,
+{}
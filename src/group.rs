@@ -0,0 +1,74 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the number of the current run of items with the same key, and the
+/// iteration item.
+///
+/// This `struct` is created by the [`index_group_number`](crate::IndexerIterator::index_group_number)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct GroupIndexer<I, T, K, F> {
+    iter: I,
+    counter: T,
+    step: T,
+    last_key: Option<K>,
+    key: F,
+}
+
+impl<I, T, K, F> GroupIndexer<I, T, K, F> {
+    pub fn new(iter: I, start: T, step: T, key: F) -> GroupIndexer<I, T, K, F> {
+        GroupIndexer { iter, counter: start, step, last_key: None, key }
+    }
+}
+
+impl<I, T, K, F> Iterator for GroupIndexer<I, T, K, F>
+where
+    I: Iterator,
+    T: IndexValue,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let key = (self.key)(&item);
+        match &self.last_key {
+            Some(last_key) if *last_key != key => self.counter.add_assign_ref(&self.step),
+            _ => {}
+        }
+        self.last_key = Some(key);
+        Some((self.counter.clone(), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, K, F> ExactSizeIterator for GroupIndexer<I, T, K, F>
+where
+    I: ExactSizeIterator,
+    T: IndexValue,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T, K, F> FusedIterator for GroupIndexer<I, T, K, F>
+where
+    I: FusedIterator,
+    T: IndexValue,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{}
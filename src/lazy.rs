@@ -0,0 +1,68 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the current count and the iteration item, the start value of the count
+/// being computed by a closure when the first item is pulled.
+///
+/// This `struct` is created by the [`index_start_with`](crate::IndexerIterator::index_start_with)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct LazyIndexer<I, T, F> {
+    iter: I,
+    counter: Option<T>,
+    step: T,
+    start: Option<F>,
+}
+
+impl<I, T, F> LazyIndexer<I, T, F> {
+    pub fn new(iter: I, start: F, step: T) -> LazyIndexer<I, T, F> {
+        LazyIndexer { iter, counter: None, step, start: Some(start) }
+    }
+}
+
+impl<I, T, F> Iterator for LazyIndexer<I, T, F>
+where
+    I: Iterator,
+    T: IndexValue,
+    F: FnOnce() -> T,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let start = &mut self.start;
+        let counter = self.counter.get_or_insert_with(|| (start.take().unwrap())());
+        let result = Some((counter.clone(), item));
+        counter.add_assign_ref(&self.step);
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, F> ExactSizeIterator for LazyIndexer<I, T, F>
+where
+    I: ExactSizeIterator,
+    T: IndexValue,
+    F: FnOnce() -> T,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T, F> FusedIterator for LazyIndexer<I, T, F>
+where
+    I: FusedIterator,
+    T: IndexValue,
+    F: FnOnce() -> T,
+{}
@@ -26,38 +26,2536 @@
 //! assert_eq!(result.next(), Some((100, 'a')));
 //! assert_eq!(result.nth(5), Some((160, 'g')));
 //! ```
+//!
+//! The crate is `no_std` when the default `std` feature is disabled. The core indexing methods are
+//! still available, but the adapters which need an allocator, the clock, the file system or threads
+//! aren't.
+//!
+//! The index type only needs to be cloned and added by reference for the core methods with an explicit
+//! start and step, which is expressed by the [`IndexValue`] trait, so types like `num_rational::Ratio<i64>`
+//! can be used with `index_step()` to get exact fractional indices. The `rational` feature also implements [`CheckedIndex`] for `Ratio`.
+//!
+//! The indices are calculated from references, without cloning the index at each step, so arbitrary-precision
+//! integers like `num_bigint::BigUint` are efficient index types for streams that could overflow `u64`. The
+//! `bigint` feature implements [`CheckedIndex`] for `BigUint` and `BigInt`.
+//!
+//! The `index()` and `index_start()` methods need `u8: Into<T>` to make the default start and step values.
+//! The `num` feature adds `index_num()` and `index_num_start()`, which take them from the `num_traits::Zero`
+//! and `num_traits::One` traits instead, so any numeric type of the `num` ecosystem can be used.
+//!
+//! The `serde` feature makes the counter of an [`Indexer`] serializable, as a [`CounterState`], so a long-running
+//! indexed job can be checkpointed and resumed with `index_resume()`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(inplace_iteration, min_specialization, trusted_len, try_trait_v2, iter_advance_by))]
 
-use std::fmt::Debug;
-use std::ops::{Add, AddAssign, Mul};
+use core::fmt::{Display, Formatter};
+use core::iter::FusedIterator;
+use core::ops::{Add, Div, Mul, Sub};
 
 mod tests;
+mod macros;
+mod value;
+mod reset;
+#[cfg(feature = "std")]
+mod outline;
+#[cfg(feature = "std")]
+mod shared;
+mod chain;
+mod flatten;
+mod product;
+#[cfg(feature = "std")]
+mod throughput;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod every;
+mod every_n;
+mod cancel;
+#[cfg(feature = "std")]
+mod budget;
+#[cfg(feature = "std")]
+mod paced;
+mod rev;
+#[cfg(feature = "std")]
+mod blocks;
+#[cfg(feature = "std")]
+mod visited;
+#[cfg(feature = "std")]
+mod checkpoint;
+mod placeholder;
+#[cfg(feature = "table")]
+mod table;
+mod lazy;
+#[cfg(feature = "std")]
+mod multipeek;
+mod context;
+mod sorted;
+#[cfg(feature = "std")]
+mod path;
+mod preorder;
+#[cfg(feature = "std")]
+mod chunks;
+#[cfg(feature = "std")]
+mod collection;
+mod entries;
+#[cfg(feature = "std")]
+mod parallel;
+#[cfg(feature = "crossbeam")]
+mod fanout;
+#[cfg(feature = "std")]
+mod reorder;
+#[cfg(feature = "std")]
+mod merge;
+mod group;
+#[cfg(feature = "std")]
+mod elapsed;
+mod bits;
+mod serial;
+mod tiled;
+mod array;
+mod checked;
+mod saturating;
+mod overflow;
+mod desc;
+mod from_end;
+mod signed;
+mod scale;
+mod generated;
+mod by;
+mod alpha;
+mod roman;
+mod ordinal;
+#[cfg(feature = "std")]
+mod format;
+mod radix;
+mod padded;
+mod grid;
+mod cycles;
+mod paged;
+#[cfg(feature = "std")]
+mod per_group;
+mod conditional;
+mod modular;
+mod duration;
+mod nonzero;
+mod mixed;
+mod const_step;
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "stream")]
+mod stream;
+#[cfg(feature = "tokio")]
+mod receiver;
+#[cfg(feature = "serde")]
+mod state;
+#[cfg(feature = "chrono")]
+mod dates;
+#[cfg(feature = "time")]
+mod calendar;
+
+pub use value::IndexValue;
+use value::add_steps;
+pub use reset::ResetIndexer;
+#[cfg(feature = "std")]
+pub use outline::{Outline, OutlineIndexer};
+#[cfg(feature = "std")]
+pub use shared::{SharedIndex, SharedIndexer};
+pub use chain::{chain_indexed, ChainIndexer};
+pub use flatten::FlattenIndexer;
+pub use product::ProductIndexer;
+#[cfg(feature = "std")]
+pub use throughput::{Throughput, ThroughputIter};
+#[cfg(feature = "metrics")]
+pub use crate::metrics::MetricsIter;
+pub use every::OnEvery;
+pub use every_n::EveryIndexer;
+pub use cancel::Cancellable;
+#[cfg(feature = "std")]
+pub use budget::Budgeted;
+#[cfg(feature = "std")]
+pub use paced::Paced;
+pub use rev::RevIndexer;
+#[cfg(feature = "std")]
+pub use blocks::{IndexedBlocks, IndexerRead, ShortBlock};
+#[cfg(feature = "std")]
+pub use visited::{TrackVisited, Visited};
+#[cfg(feature = "std")]
+pub use checkpoint::{Checkpoint, Checkpointed};
+pub use placeholder::{Placeholder, PlaceholderIndexer, PlaceholderStyle};
+pub use lazy::LazyIndexer;
+#[cfg(feature = "std")]
+pub use multipeek::MultiPeek;
+pub use context::Context;
+pub use sorted::{CheckSorted, CheckSortedByKey, UnsortedError};
+#[cfg(feature = "std")]
+pub use path::{IndexPath, Nested, PathIndexer};
+pub use preorder::PreorderIndexer;
+#[cfg(feature = "std")]
+pub use chunks::IndexedChunks;
+#[cfg(feature = "std")]
+pub use collection::IndexedCollection;
+pub use entries::EntryIndexer;
+#[cfg(feature = "crossbeam")]
+pub use fanout::{FanOut, Receivers, RoundRobin};
+#[cfg(feature = "std")]
+pub use reorder::{Reorder, ReorderError};
+#[cfg(feature = "std")]
+pub use merge::{merge_indexed, MergeIndexed};
+pub use group::GroupIndexer;
+#[cfg(feature = "std")]
+pub use elapsed::ElapsedIndexer;
+pub use bits::{BitIndexer, BitOrder};
+pub use serial::SerialNumber;
+pub use tiled::TiledIndexer;
+pub use array::IndexedArray;
+pub use checked::{CheckedIndex, CheckedIndexer, IndexError};
+pub use saturating::{SaturatingIndex, SaturatingIndexer};
+pub use overflow::{OverflowIndex, OverflowIndexer, OverflowPolicy};
+pub use desc::DescIndexer;
+pub use from_end::FromEndIndexer;
+pub use signed::{SignedStep, SignedStepIndexer};
+pub use scale::ScaleIndexer;
+pub use generated::{IndexSuccessors, IndexWith};
+pub use by::{Exhaustion, IndexBy};
+pub use alpha::{Alpha, AlphaIndexer, LetterCase};
+pub use roman::{Roman, RomanIndexer};
+pub use ordinal::{Ordinal, OrdinalIndexer};
+#[cfg(feature = "std")]
+pub use format::FormatIndexer;
+pub use radix::{RadixIndexer, RadixLabel};
+pub use padded::{Padded, PaddedIndexer};
+pub use grid::{Index2d, Index3d, IndexNd};
+pub use cycles::CycleIndexer;
+pub use paged::PagedIndexer;
+#[cfg(feature = "std")]
+pub use per_group::PerGroupIndexer;
+pub use conditional::ConditionalIndexer;
+pub use modular::ModIndexer;
+pub use duration::DurationIndexer;
+pub use nonzero::{NonZeroIndex, NonZeroIndexer};
+pub use mixed::MixedStepIndexer;
+pub use const_step::ConstStepIndexer;
+#[cfg(feature = "rayon")]
+pub use par::{ParIndexer, ParallelIndexerIterator, ParallelReorder};
+#[cfg(feature = "stream")]
+pub use stream::{IndexerStreamExt, StreamIndexer};
+#[cfg(feature = "tokio")]
+pub use receiver::IndexedReceiver;
+#[cfg(feature = "serde")]
+pub use state::CounterState;
+#[cfg(feature = "chrono")]
+pub use dates::DateIndexer;
+#[cfg(feature = "time")]
+pub use calendar::{CalendarIndex, CalendarIndexer, CalendarStep};
 
 //------------------------------------------------------------------------------
 
-/// An iterator that yields the current count, with the generic type, and the iteration item.
-#[derive(Clone, Debug)]
-#[must_use = "iterators are lazy and do nothing unless consumed"]
-pub struct Indexer<I, T> {
-    iter: I,
-    counter: T,
-    step: T
-}
+/// An iterator that yields the current count, with the generic type, and the iteration item.
+///
+/// The index type defaults to `usize`, like the count of `Enumerate`, so `Indexer<I>` can be used in
+/// type annotations:
+///
+/// ```
+/// use iter_index::{Indexer, IndexerIterator};
+///
+/// let items: Indexer<_> = ["a", "b"].into_iter().index();
+/// assert_eq!(items.collect::<Vec<_>>(), vec![(0_usize, "a"), (1, "b")]);
+/// ```
+///
+/// When the size hint of the source iterator is exact, as it is for an `ExactSizeIterator`, `last()`
+/// calculates the last index directly instead of counting the items, so it relies on that hint being
+/// correct. With the `nightly` feature, it only does so for the source iterators which implement
+/// `TrustedLen`.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Indexer<I, T = usize> {
+    iter: I,
+    counter: T,
+    step: T,
+    // the start value, recorded when the counter moves away from it for the first time
+    start: Option<T>,
+}
+
+impl<I, T> Indexer<I, T> {
+    pub fn new(iter: I, start: T, step: T) -> Indexer<I, T> {
+        Indexer { iter, counter: start, step, start: None }
+    }
+
+    /// Returns the index of the next item, which is the current value of the counter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let mut items = "abc".chars().index_step::<u8>(10, 5);
+    /// assert_eq!(items.find(|(_, c)| *c == 'b'), Some((15, 'b')));
+    /// assert_eq!(items.current_index(), &20);
+    /// assert_eq!(items.step(), &5);
+    /// ```
+    pub fn current_index(&self) -> &T {
+        &self.counter
+    }
+
+    /// Returns the step added to the index after each item.
+    pub fn step(&self) -> &T {
+        &self.step
+    }
+
+    /// Consumes the adapter and returns the source iterator, without exhausting it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let mut items = (0..5).index::<u8>();
+    /// assert_eq!(items.next(), Some((0, 0)));
+    /// assert_eq!(items.into_inner().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    /// Sets the index of the next item to `value`. The following indices are incremented from there
+    /// by the same step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let text = "a\nb\n[section]\nc\nd";
+    /// let mut lines = text.lines().index_start::<u32>(1);
+    /// let mut result = Vec::new();
+    /// while let Some((n, line)) = lines.next() {
+    ///     if line.starts_with('[') {
+    ///         lines.set_index(1);
+    ///     } else {
+    ///         result.push((n, line));
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(result, vec![(1, "a"), (2, "b"), (1, "c"), (2, "d")]);
+    /// ```
+    pub fn set_index(&mut self, value: T) {
+        self.move_counter(value);
+    }
+
+    /// Sets the index of the next item back to the start value given when the iterator was created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let mut items = "abcd".chars().index_step::<u8>(10, 10);
+    /// assert_eq!(items.nth(1), Some((20, 'b')));
+    /// items.reset();
+    /// assert_eq!(items.collect::<Vec<_>>(), vec![(10, 'c'), (20, 'd')]);
+    /// ```
+    pub fn reset(&mut self) {
+        if let Some(start) = self.start.take() {
+            self.counter = start;
+        }
+    }
+
+    /// Sets the counter to `counter`, recording the start value if the counter moves away from it for
+    /// the first time.
+    #[inline]
+    fn move_counter(&mut self, counter: T) {
+        let previous = core::mem::replace(&mut self.counter, counter);
+        if self.start.is_none() {
+            self.start = Some(previous);
+        }
+    }
+
+    /// Reverses the iteration direction of a source iterator which doesn't implement `ExactSizeIterator`,
+    /// while keeping the indices of the forward direction, the total number of items being given by `len`.
+    ///
+    /// `len` must be the number of items remaining in the source iterator. This is useful when the
+    /// length is known from an earlier pass, for instance on a `Filter` iterator.
+    ///
+    /// # Panics
+    ///
+    /// The returned iterator panics if the source iterator yields more than `len` items. If it yields
+    /// fewer items, the indices are shifted by the difference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let evens = (0..10).filter(|x| x % 2 == 0);
+    /// let len = evens.clone().count();
+    /// let result = evens.index_start::<u8>(1).rev_with_len(len).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![(5, 8), (4, 6), (3, 4), (2, 2), (1, 0)]);
+    /// ```
+    pub fn rev_with_len(self, len: usize) -> RevIndexer<I, T> where I: DoubleEndedIterator {
+        RevIndexer::new(self.iter, self.counter, self.step, len)
+    }
+
+    /// Converts the iterator into an iterator which handles the overflow of the index according to
+    /// `policy`, continuing from the current index. The policy can thus be selected at runtime,
+    /// for instance from a configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::{IndexerIterator, OverflowPolicy};
+    ///
+    /// let items = (0..5).index_start::<u8>(253);
+    /// let result = items.clone().with_overflow_policy(OverflowPolicy::Wrap).collect::<Vec<_>>();
+    /// assert_eq!(result, vec![(253, 0), (254, 1), (255, 2), (0, 3), (1, 4)]);
+    ///
+    /// let result = items.with_overflow_policy(OverflowPolicy::StopIteration).collect::<Vec<_>>();
+    /// assert_eq!(result, vec![(253, 0), (254, 1), (255, 2)]);
+    /// ```
+    pub fn with_overflow_policy(self, policy: OverflowPolicy) -> OverflowIndexer<I, T> {
+        OverflowIndexer::new(self.iter, self.counter, self.step, policy)
+    }
+}
+
+impl<I, T> Indexer<I, T>
+where
+    I: Iterator,
+    T: IndexValue,
+{
+    /// Consumes the iterator, counting the number of iterations and returning it along with the index
+    /// of the last item, or `None` if there was no item.
+    ///
+    /// The count is obtained from the source iterator with `count()`, and the last index is computed from
+    /// the count, so the index isn't incremented for each item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let items = (0..100).filter(|x| x % 3 == 0).index_step::<u32>(10, 5);
+    /// assert_eq!(items.count_with_last_index(), (34, Some(175)));
+    ///
+    /// let items = std::iter::empty::<()>().index::<u32>();
+    /// assert_eq!(items.count_with_last_index(), (0, None));
+    /// ```
+    pub fn count_with_last_index(self) -> (usize, Option<T>) {
+        let count = self.iter.count();
+        if count == 0 {
+            return (0, None);
+        }
+        (count, Some(add_steps(&self.counter, &self.step, count - 1)))
+    }
+}
+
+impl<I, T> Indexer<I, T>
+where
+    I: Iterator,
+    T: CheckedIndex + TryFrom<usize>,
+{
+    /// Returns the `n`th element of the iterator with its index, like `nth`, or an error if the index
+    /// can't be calculated because `n` can't be converted to `T` or because the index type overflows.
+    ///
+    /// The source iterator isn't advanced when an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::{IndexError, IndexerIterator};
+    ///
+    /// let mut items = (0..1000).index_step::<u8>(100, 10);
+    /// assert_eq!(items.try_nth(2), Ok(Some((120, 2))));
+    /// assert_eq!(items.try_nth(300), Err(IndexError::ConversionFailed));
+    /// assert_eq!(items.try_nth(20), Err(IndexError::Overflow));
+    /// assert_eq!(items.try_nth(0), Ok(Some((130, 3))));
+    /// ```
+    pub fn try_nth(&mut self, n: usize) -> Result<Option<(T, I::Item)>, IndexError> {
+        let nn = T::try_from(n).map_err(|_| IndexError::ConversionFailed)?;
+        let i = nn.checked_mul(&self.step)
+            .and_then(|offset| self.counter.checked_add(&offset))
+            .ok_or(IndexError::Overflow)?;
+        // the next index must be valid, too
+        let counter = i.checked_add(&self.step).ok_or(IndexError::Overflow)?;
+        match self.iter.nth(n) {
+            Some(v) => {
+                self.move_counter(counter);
+                Ok(Some((i, v)))
+            }
+            None => Ok(None)
+        }
+    }
+}
+
+impl<I: Iterator> Indexer<I, usize> {
+    /// Converts the iterator into the equivalent `Enumerate` iterator of the standard library, if the
+    /// next index is 0 and the step is 1, or gives it back otherwise.
+    ///
+    /// `Enumerate` always counts from 0 and doesn't allow to set its count, so an `Indexer` which has
+    /// already yielded items or which uses another start value or step can't be converted.
+    ///
+    /// For the same reason, and because `Enumerate` doesn't give access to its source iterator, there's no
+    /// conversion in the other direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let items = vec!["a", "b"].into_iter().index::<usize>();
+    /// let result: std::iter::Enumerate<_> = items.into_enumerate().unwrap();
+    /// assert_eq!(result.collect::<Vec<_>>(), vec![(0, "a"), (1, "b")]);
+    ///
+    /// let items = vec!["a", "b"].into_iter().index_start::<usize>(1);
+    /// assert!(items.into_enumerate().is_err());
+    /// ```
+    pub fn into_enumerate(self) -> Result<core::iter::Enumerate<I>, Self> {
+        if self.counter == 0 && self.step == 1 {
+            Ok(self.iter.enumerate())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<I, T> Indexer<I, T>
+where
+    T: Copy + From<u8> + PartialEq + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Aligns the indices on `alignment`, when they are used as memory addresses or offsets.
+    ///
+    /// The next index is rounded up to the next multiple of `alignment`, and the step is padded to
+    /// a multiple of `alignment`, so that every index emitted from there is aligned, like the
+    /// offsets of the elements of an array of a padded structure.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `alignment` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let fields = vec!["a", "b", "c"];
+    /// let result = fields.into_iter().index_step::<usize>(0x1001, 12).aligned_to(8).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![(0x1008, "a"), (0x1018, "b"), (0x1028, "c")]);
+    /// ```
+    pub fn aligned_to(self, alignment: T) -> Indexer<I, T> {
+        let zero = T::from(0);
+        let one = T::from(1);
+        assert!(alignment != zero, "alignment must be greater than 0");
+        let align_up = |x: T| (x + (alignment - one)) / alignment * alignment;
+        Indexer { iter: self.iter, counter: align_up(self.counter), step: align_up(self.step), start: self.start.map(align_up) }
+    }
+}
+
+pub trait IndexerIterator: Iterator {
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and contains the current
+    /// index of iteration, and `val` is the value returned by the source iterator.
+    ///
+    /// `index::<T>()` starts counting at 0 and increments by 1. If you need another start value, use
+    /// `index_start::<T>(start: T)` instead. If you need different steps than 1, use
+    /// `index_step::<T>(start: T, step: T)`.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let items = vec!["a", "b", "c"];
+    /// let mut result = items.into_iter().index::<i32>();
+    ///
+    /// assert_eq!(result.next(), Some((0_i32, "a")));
+    /// assert_eq!(result.next(), Some((1_i32, "b")));
+    /// assert_eq!(result.next(), Some((2_i32, "c")));
+    /// assert_eq!(result.next(), None);
+    /// ```
+    fn index<T>(self) -> Indexer<Self, T> where Self: Sized, u8: Into<T> {
+        Indexer::new(self, 0.into(), 1.into())
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and contains the current
+    /// index of iteration, and `val` is the value returned by the source iterator.
+    ///
+    /// `index_start::<T>(start: T)` starts counting at `start` and increments by 1. If you need different
+    /// steps than 1, use `index_step::<T>(start: T, step: T)`.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let items = vec!["a", "b", "c"];
+    /// let mut result = items.into_iter().index_start::<u8>(97);
+    ///
+    /// assert_eq!(result.next(), Some((97_u8, "a")));
+    /// assert_eq!(result.next(), Some((98_u8, "b")));
+    /// assert_eq!(result.next(), Some((99_u8, "c")));
+    /// assert_eq!(result.next(), None);
+    /// ```
+    fn index_start<T>(self, start: T) -> Indexer<Self, T> where Self: Sized, u8: Into<T> {
+        Indexer::new(self, start, 1.into())
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and contains the current
+    /// index of iteration, and `val` is the value returned by the source iterator.
+    ///
+    /// `index_step::<T>(start: T, step: T)` starts counting at `start` and increments by `step`.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let items = vec!["a", "b", "c"];
+    /// let mut result = items.into_iter().index_step::<u32>(100, 10);
+    ///
+    /// assert_eq!(result.next(), Some((100_u32, "a")));
+    /// assert_eq!(result.next(), Some((110_u32, "b")));
+    /// assert_eq!(result.next(), Some((120_u32, "c")));
+    /// assert_eq!(result.next(), None);
+    /// ```
+    fn index_step<T>(self, start: T, step: T) -> Indexer<Self, T> where Self: Sized {
+        Indexer::new(self, start, step)
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself,
+    /// restarting the index at 0 each time `predicate` returns `true` for an item.
+    ///
+    /// The item for which the predicate matches gets the index 0, and the following items are
+    /// numbered from there until the next match. This gives a section-relative numbering when the
+    /// predicate detects the boundaries of the sections (blank lines, headers, sync bytes, ...).
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let lines = vec!["# A", "a1", "a2", "# B", "b1"];
+    /// let result = lines.into_iter().index_reset_when::<u32, _>(|s| s.starts_with('#')).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![(0, "# A"), (1, "a1"), (2, "a2"), (0, "# B"), (1, "b1")]);
+    /// ```
+    fn index_reset_when<T, P>(self, predicate: P) -> ResetIndexer<Self, T, P>
+    where
+        Self: Sized,
+        u8: Into<T>,
+        T: Clone,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        ResetIndexer::new(self, 0.into(), 1.into(), predicate)
+    }
+
+    /// Creates an iterator which gives a hierarchical outline label of the source iterator value
+    /// as well as the value itself, like the numbering of the sections in a document.
+    ///
+    /// The `depth` closure gives the level of each item, 0 being the top level. The iterator yields
+    /// pairs `(label, val)`, where `label` is an [`Outline`] holding the index at each level of the
+    /// hierarchy, from the top level down to the level of `val`. Each level starts counting at 1.
+    /// If an item is more than one level deeper than the previous one, the skipped levels are
+    /// numbered 1.
+    ///
+    /// [`Outline`] implements `Display` when `T` does, which renders the label as `1.2.1`.
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let titles = vec!["Intro", "  Scope", "  Terms", "Usage", "  Basics"];
+    /// let result = titles.into_iter()
+    ///     .index_outline::<u32, _>(|s| (s.len() - s.trim_start().len()) / 2)
+    ///     .map(|(label, s)| format!("{label} {}", s.trim()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec!["1 Intro", "1.1 Scope", "1.2 Terms", "2 Usage", "2.1 Basics"]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn index_outline<T, F>(self, depth: F) -> OutlineIndexer<Self, T, F>
+    where
+        Self: Sized,
+        u8: Into<T>,
+        F: FnMut(&Self::Item) -> usize,
+    {
+        OutlineIndexer::new(self, 1.into(), 1.into(), depth)
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself,
+    /// the index being drawn from a [`SharedIndex`] counter.
+    ///
+    /// The counter is shared with all the other iterators created with the same [`SharedIndex`], so the
+    /// numbering continues seamlessly from one iterator to the next, without having to carry the last
+    /// index manually between them.
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::{IndexerIterator, SharedIndex};
+    ///
+    /// let counter = SharedIndex::<u8>::new(0, 1);
+    /// let first = vec!["a", "b"].into_iter().index_shared(&counter).collect::<Vec<_>>();
+    /// let second = vec!["c"].into_iter().index_shared(&counter).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(first, vec![(0, "a"), (1, "b")]);
+    /// assert_eq!(second, vec![(2, "c")]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn index_shared<T>(self, index: &SharedIndex<T>) -> SharedIndexer<Self, T> where Self: Sized {
+        SharedIndexer::new(self, index.clone())
+    }
+
+    /// Creates an iterator which flattens an iterator of iterables, giving the index of each
+    /// sub-iterable and the index of the value inside it, as well as the value itself.
+    ///
+    /// The iterator yields pairs `((outer, inner), val)`, where `outer` is the index of the sub-iterable
+    /// which contains `val`, and `inner` is the position of `val` in that sub-iterable. Both indices are
+    /// of type `T`, and start counting at 0 and increment by 1. Empty sub-iterables produce no item,
+    /// but they still count in the outer index.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let lists = vec![vec!["a", "b"], vec![], vec!["c"]];
+    /// let result = lists.into_iter().flatten_indexed::<u8>().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![((0, 0), "a"), ((0, 1), "b"), ((2, 0), "c")]);
+    /// ```
+    fn flatten_indexed<T>(self) -> FlattenIndexer<Self, T>
+    where
+        Self: Sized,
+        Self::Item: IntoIterator,
+        u8: Into<T>,
+        T: Clone,
+    {
+        FlattenIndexer::new(self, 0.into(), 1.into())
+    }
+
+    /// Creates an iterator which maps each value of the source iterator to an iterable and flattens
+    /// the result, giving the index of the source value and the index of each item produced from it,
+    /// as well as the item itself.
+    ///
+    /// This is equivalent to `map(f).flatten_indexed::<T>()`; see
+    /// [`flatten_indexed`](IndexerIterator::flatten_indexed) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let words = vec!["ab", "c"];
+    /// let result = words.into_iter().flat_map_indexed::<u32, _, _>(|w| w.chars()).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![((0, 0), 'a'), ((0, 1), 'b'), ((1, 0), 'c')]);
+    /// ```
+    fn flat_map_indexed<T, U, F>(self, f: F) -> FlattenIndexer<core::iter::Map<Self, F>, T>
+    where
+        Self: Sized,
+        U: IntoIterator,
+        F: FnMut(Self::Item) -> U,
+        u8: Into<T>,
+        T: Clone,
+    {
+        FlattenIndexer::new(self.map(f), 0.into(), 1.into())
+    }
+
+    /// Creates an iterator over the cartesian product of the source iterator and `other`, which gives
+    /// the pair of indices `(i, j)` as well as the pair of values.
+    ///
+    /// The iterator yields pairs `((i, j), (a, b))`, where `a` is the value of index `i` in the source
+    /// iterator and `b` is the value of index `j` in `other`. Both indices are of type `T`, and start
+    /// counting at 0 and increment by 1. `other` is cloned for each value of the source iterator, and
+    /// the values of the source iterator are cloned for each value of `other`.
+    ///
+    /// When both iterators implement `ExactSizeIterator`, so does the resulting iterator.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let rows = vec!["a", "b"];
+    /// let cols = vec![10, 20];
+    /// let result = rows.into_iter().product_indexed::<u8, _>(cols).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![
+    ///     ((0, 0), ("a", 10)), ((0, 1), ("a", 20)),
+    ///     ((1, 0), ("b", 10)), ((1, 1), ("b", 20)),
+    /// ]);
+    /// ```
+    fn product_indexed<T, J>(self, other: J) -> ProductIndexer<Self, J::IntoIter, T>
+    where
+        Self: Sized,
+        J: IntoIterator,
+        J::IntoIter: Clone,
+        u8: Into<T>,
+        T: Clone,
+    {
+        ProductIndexer::new(self, other.into_iter(), 0.into(), 1.into())
+    }
+
+    /// Creates an iterator which yields the same values as the source iterator, while measuring the
+    /// rate at which they're produced.
+    ///
+    /// The rate, in items per second, is measured over successive windows of at least `window`, using
+    /// a monotonic clock. It can be read with the [`Throughput`] handle returned by
+    /// [`ThroughputIter::meter`], which remains usable while the iterator is consumed by a loop.
+    ///
+    /// The adapter can be used on any iterator, including the indexed iterators of this crate.
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let items = (0..1000).with_throughput(Duration::from_millis(1));
+    /// let meter = items.meter();
+    /// for (i, item) in items.index::<u32>() {
+    ///     // ...
+    /// #   let _ = (i, item);
+    /// }
+    ///
+    /// assert_eq!(meter.count(), 1000);
+    /// if let Some(rate) = meter.rate() {
+    ///     println!("{rate:.0} items/s");
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    fn with_throughput(self, window: std::time::Duration) -> ThroughputIter<Self> where Self: Sized {
+        ThroughputIter::new(self, window)
+    }
+
+    /// Creates an iterator which yields the same values as the source iterator, while incrementing
+    /// a [`metrics::counter!`] named `counter_name` for each value.
+    ///
+    /// The counter is registered when the adapter is created, with the recorder installed at that time.
+    /// A gauge can also be set for each item with [`MetricsIter::with_gauge`], for example to
+    /// report the current typed index.
+    ///
+    /// This method requires the `metrics` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let items = vec!["a", "b", "c"];
+    /// let result = items.into_iter().index::<u8>().record_metrics("items_processed").collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![(0, "a"), (1, "b"), (2, "c")]);
+    /// ```
+    #[cfg(feature = "metrics")]
+    fn record_metrics<N>(self, counter_name: N) -> MetricsIter<Self, fn(&Self::Item) -> f64>
+    where
+        Self: Sized,
+        N: Into<::metrics::KeyName>,
+    {
+        let name = counter_name.into();
+        MetricsIter::new(self, ::metrics::counter!(name))
+    }
+
+    /// Creates an iterator which yields the same indexed values as the source iterator, while calling
+    /// `f(&i, &val)` on every `n`-th pair `(i, val)`, for example to log a heartbeat or to flush
+    /// intermediate results periodically.
+    ///
+    /// The source iterator must yield pairs `(i, val)`, like the indexed iterators of this crate.
+    /// The closure is first called on the `n`-th pair, then on the `2n`-th, and so on, before the pair
+    /// is yielded. The adapter keeps the size hint and the fused-ness of the source iterator.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let mut log = Vec::new();
+    /// let total = (0..10)
+    ///     .index_start::<u32>(1)
+    ///     .on_every(4, |i, val| log.push(format!("{i} items, last = {val}")))
+    ///     .count();
+    ///
+    /// assert_eq!(total, 10);
+    /// assert_eq!(log, vec!["4 items, last = 3", "8 items, last = 7"]);
+    /// ```
+    fn on_every<T, V, F>(self, n: usize, f: F) -> OnEvery<Self, F>
+    where
+        Self: Iterator<Item = (T, V)> + Sized,
+        F: FnMut(&T, &V),
+    {
+        OnEvery::new(self, n, f)
+    }
+
+    /// Creates an iterator which yields the same indexed values as the source iterator, until `flag`
+    /// is set.
+    ///
+    /// The source iterator must yield pairs `(i, val)`, like the indexed iterators of this crate.
+    /// The flag is checked before pulling each item from the source iterator, so no item is lost when
+    /// the iteration is cancelled: [`Cancellable::last_index`] gives the index of the last item yielded,
+    /// and [`Cancellable::into_inner`] returns the source iterator, ready to resume the iteration.
+    ///
+    /// The iterator isn't fused: it yields items again if the flag is cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let cancel = AtomicBool::new(false);
+    /// let mut job = (0..100).index::<u32>().until_cancelled(&cancel);
+    /// for (i, _val) in job.by_ref() {
+    ///     if i == 41 {
+    ///         cancel.store(true, Ordering::Relaxed);
+    ///     }
+    /// }
+    ///
+    /// assert!(job.is_cancelled());
+    /// assert_eq!(job.last_index(), Some(&41));
+    /// assert_eq!(job.into_inner().next(), Some((42, 42)));
+    /// ```
+    fn until_cancelled<T, V>(self, flag: &core::sync::atomic::AtomicBool) -> Cancellable<'_, Self, T>
+    where
+        Self: Iterator<Item = (T, V)> + Sized,
+    {
+        Cancellable::new(self, flag)
+    }
+
+    /// Creates an iterator which yields the same indexed values as the source iterator, until the
+    /// time `budget` is exhausted.
+    ///
+    /// The source iterator must yield pairs `(i, val)`, like the indexed iterators of this crate.
+    /// The budget starts when the first item is requested, and is checked with a monotonic clock before
+    /// pulling each item from the source iterator, so no item is lost when it runs out:
+    /// [`Budgeted::last_index`] gives the index of the last item yielded, and [`Budgeted::renew`] gives
+    /// a new budget to continue the iteration later, for example on the next frame.
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let mut work = (0..1000).index::<u32>().within_budget(Duration::from_millis(2));
+    /// let mut processed = 0;
+    /// loop {
+    ///     for (_i, _val) in work.by_ref() {
+    ///         processed += 1;
+    ///     }
+    ///     if !work.is_exhausted() {
+    ///         break;
+    ///     }
+    ///     // ... next tick
+    ///     work.renew(Duration::from_millis(2));
+    /// }
+    ///
+    /// assert_eq!(processed, 1000);
+    /// assert_eq!(work.last_index(), Some(&999));
+    /// ```
+    #[cfg(feature = "std")]
+    fn within_budget<T, V>(self, budget: std::time::Duration) -> Budgeted<Self, T>
+    where
+        Self: Iterator<Item = (T, V)> + Sized,
+    {
+        Budgeted::new(self, budget)
+    }
+
+    /// Creates an iterator which yields the same values as the source iterator, at a fixed rate.
+    ///
+    /// The iterator sleeps when necessary so that the `k`-th value, counted from 0, isn't yielded before
+    /// `start + k * step`, where `start` is the time at which the first value was requested. If the
+    /// consumer is late, the values are yielded without waiting until the schedule is caught up.
+    ///
+    /// The adapter can be used on any iterator, including the indexed iterators of this crate, for
+    /// example to replay a recorded sequence at its original rate.
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let start = Instant::now();
+    /// let result = vec!["a", "b", "c"].into_iter()
+    ///     .index::<u8>()
+    ///     .paced(Duration::from_millis(5))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![(0, "a"), (1, "b"), (2, "c")]);
+    /// assert!(start.elapsed() >= Duration::from_millis(10));
+    /// ```
+    #[cfg(feature = "std")]
+    fn paced(self, step: std::time::Duration) -> Paced<Self> where Self: Sized {
+        Paced::new(self, step)
+    }
+
+    /// Creates an iterator which yields the same indexed values as the source iterator, while recording
+    /// the index of each value in a [`Visited`] set.
+    ///
+    /// The source iterator must yield pairs `(i, val)`, like the indexed iterators of this crate, and the
+    /// indices must be convertible to `u32`. The set can be read at any time with [`TrackVisited::visited`],
+    /// for example to find which positions remain to be processed after a partial iteration.
+    ///
+    /// The set is a [`roaring::RoaringBitmap`](https://docs.rs/roaring) when the `roaring` feature is
+    /// enabled, which is more compact for large and sparse sets, and a plain bit vector otherwise.
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if an index can't be converted to `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let mut job = (0..10).index::<u32>().filter(|(_, x)| x % 3 != 0).track_visited();
+    /// for (_i, _x) in job.by_ref().take(4) {
+    ///     // ...
+    /// }
+    ///
+    /// let todo = job.visited().missing(10).collect::<Vec<_>>();
+    /// assert_eq!(todo, vec![0, 3, 6, 7, 8, 9]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn track_visited<T, V>(self) -> TrackVisited<Self>
+    where
+        Self: Iterator<Item = (T, V)> + Sized,
+    {
+        TrackVisited::new(self)
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself,
+    /// resuming from the state saved in the file `path` if it exists.
+    ///
+    /// If the file exists, the start value and the step are read from it, and the number of items consumed
+    /// at the time of the checkpoint are skipped from the source iterator. Otherwise, the iterator starts
+    /// counting at `start` and increments by `step`, like [`index_step`](IndexerIterator::index_step).
+    /// The state can be saved at any time with [`Checkpointed::checkpoint_to`].
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// # Errors
+    ///
+    /// The method returns an error if the file exists but can't be read or parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let path = std::env::temp_dir().join("iter_index_resume_from_doc.txt");
+    /// # let _ = std::fs::remove_file(&path);
+    /// let mut job = (0..10).resume_from::<u32, _>(&path, 100, 10)?;
+    /// assert_eq!(job.nth(3), Some((130, 3)));
+    /// job.checkpoint_to(&path)?;
+    ///
+    /// // after a crash
+    /// let mut job = (0..10).resume_from::<u32, _>(&path, 100, 10)?;
+    /// assert_eq!(job.next(), Some((140, 4)));
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    fn resume_from<T, P>(self, path: P, start: T, step: T) -> std::io::Result<Checkpointed<Self, T>>
+    where
+        Self: Sized,
+        P: AsRef<std::path::Path>,
+        T: std::str::FromStr + IndexValue,
+    {
+        let checkpoint = match Checkpoint::load(path) {
+            Ok(checkpoint) => checkpoint,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Checkpoint { start, step, consumed: 0 },
+            Err(e) => return Err(e),
+        };
+        Ok(Checkpointed::new(self, checkpoint))
+    }
+
+    /// Creates an iterator which gives an SQL bind parameter placeholder for each value of the source
+    /// iterator, as well as the value itself.
+    ///
+    /// The iterator yields pairs `(placeholder, val)`, where `placeholder` is a [`Placeholder`] whose
+    /// position starts at 1 and which is displayed according to `style`, for example `$1`, `$2`, ...
+    /// This allows to build the list of placeholders of a query and the list of parameters to bind in a
+    /// single pass.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::{IndexerIterator, PlaceholderStyle};
+    ///
+    /// let values = vec!["alice", "bob"];
+    /// let (marks, params): (Vec<_>, Vec<_>) = values.into_iter()
+    ///     .index_placeholders(PlaceholderStyle::Dollar)
+    ///     .map(|(p, v)| (p.to_string(), v))
+    ///     .unzip();
+    /// let query = format!("INSERT INTO users (name) VALUES ({})", marks.join("), ("));
+    ///
+    /// assert_eq!(query, "INSERT INTO users (name) VALUES ($1), ($2)");
+    /// assert_eq!(params, vec!["alice", "bob"]);
+    /// ```
+    fn index_placeholders(self, style: PlaceholderStyle) -> PlaceholderIndexer<Self> where Self: Sized {
+        PlaceholderIndexer::new(self, style)
+    }
+
+    /// Renders the indexed values of the source iterator as an aligned text table, with the titles
+    /// `headers` for the index column and the value column.
+    ///
+    /// The source iterator must yield pairs `(i, val)`, like the indexed iterators of this crate, whose
+    /// parts implement `Display`. The index column is aligned to the right, and the value column to the
+    /// left. Each line of the table ends with a line feed.
+    ///
+    /// This method requires the `table` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let table = vec!["apple", "kiwi"].into_iter().index_step::<u32>(8, 4).to_table(["#", "fruit"]);
+    ///
+    /// assert_eq!(table, " # | fruit\n-- | -----\n 8 | apple\n12 | kiwi\n");
+    /// ```
+    #[cfg(feature = "table")]
+    fn to_table<T, V>(self, headers: [&str; 2]) -> String
+    where
+        Self: Iterator<Item = (T, V)> + Sized,
+        T: core::fmt::Display,
+        V: core::fmt::Display,
+    {
+        table::render(self, headers)
+    }
+
+    /// Prints the indexed values of the source iterator to the standard output, as an aligned text table
+    /// with the titles `headers`; see [`to_table`](IndexerIterator::to_table) for more details.
+    ///
+    /// This method requires the `table` feature.
+    #[cfg(feature = "table")]
+    fn print_table<T, V>(self, headers: [&str; 2])
+    where
+        Self: Iterator<Item = (T, V)> + Sized,
+        T: core::fmt::Display,
+        V: core::fmt::Display,
+    {
+        print!("{}", table::render(self, headers));
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself,
+    /// the start value of the index being computed by `start` when the first value is pulled.
+    ///
+    /// `index_start_with::<T, F>(start: F)` calls `start` once, after the first value has been pulled
+    /// from the source iterator, and increments the index by 1 from there. This is useful when the
+    /// iterator is built ahead of time, and the start value depends on the moment it's consumed, like a
+    /// timestamp or the next value of a database sequence. If the source iterator is empty, `start` isn't
+    /// called.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let sequence = Cell::new(10);
+    /// let mut result = vec!["a", "b"].into_iter().index_start_with::<u32, _>(|| sequence.get());
+    /// sequence.set(20);
+    ///
+    /// assert_eq!(result.next(), Some((20, "a")));
+    /// assert_eq!(result.next(), Some((21, "b")));
+    /// ```
+    fn index_start_with<T, F>(self, start: F) -> LazyIndexer<Self, T, F>
+    where
+        Self: Sized,
+        u8: Into<T>,
+        F: FnOnce() -> T,
+    {
+        LazyIndexer::new(self, start, 1.into())
+    }
+
+    /// Creates an iterator which yields the same indexed values as the source iterator, and which allows
+    /// to peek at up to `lookahead` upcoming values with [`MultiPeek::peek_nth`], along with the index
+    /// they will carry.
+    ///
+    /// The source iterator must yield pairs `(i, val)`, like the indexed iterators of this crate. The
+    /// peeked values are kept in a buffer until they're yielded, so this is useful for parsers that need
+    /// a bounded lookahead and the position of the tokens to report errors.
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let mut tokens = vec!["let", "x", "=", "1"].into_iter().index_start::<u32>(1).multipeek_indexed(2);
+    ///
+    /// assert_eq!(tokens.peek_nth(1), Some(&(2, "x")));
+    /// assert_eq!(tokens.next(), Some((1, "let")));
+    /// assert_eq!(tokens.peek_nth(1), Some(&(3, "=")));
+    /// ```
+    #[cfg(feature = "std")]
+    fn multipeek_indexed<T, V>(self, lookahead: usize) -> MultiPeek<Self>
+    where
+        Self: Iterator<Item = (T, V)> + Sized,
+    {
+        MultiPeek::new(self, lookahead)
+    }
+
+    /// Creates an iterator which yields the indexed values of the source iterator, each one along with
+    /// the previous and the next values.
+    ///
+    /// The source iterator must yield pairs `(i, val)`, like the indexed iterators of this crate. The
+    /// iterator yields tuples `(i, prev, val, next)`, where `prev` and `next` are clones of the neighbours
+    /// of `val`, or `None` at the beginning and the end of the sequence. This is useful for processing that
+    /// depends on the neighbourhood of each value, like smoothing or delta encoding.
+    ///
+    /// The iterator reads one value ahead of the source iterator, and it's fused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let samples = vec![1, 4, 2];
+    /// let result = samples.into_iter().index::<u8>().with_context().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![
+    ///     (0, None, 1, Some(4)),
+    ///     (1, Some(1), 4, Some(2)),
+    ///     (2, Some(4), 2, None),
+    /// ]);
+    /// ```
+    fn with_context<T, V>(self) -> Context<Self, T, V>
+    where
+        Self: Iterator<Item = (T, V)> + Sized,
+        V: Clone,
+    {
+        Context::new(self)
+    }
+
+    /// Creates an iterator which yields the same indexed values as the source iterator, wrapped in `Ok`,
+    /// while they're sorted according to the comparison function `compare`.
+    ///
+    /// The source iterator must yield pairs `(i, val)`, like the indexed iterators of this crate. When
+    /// `compare(prev, val)` returns `Ordering::Greater`, where `prev` is the previous value, the iterator
+    /// yields an [`UnsortedError`] holding the index of `val` instead of the pair, then `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::{IndexerIterator, UnsortedError};
+    ///
+    /// let values = vec![1.0, 2.5, 2.0, 3.0];
+    /// let result = values.into_iter()
+    ///     .index_start::<u32>(1)
+    ///     .check_sorted_by(|a: &f64, b| a.total_cmp(b))
+    ///     .collect::<Result<Vec<_>, _>>();
+    ///
+    /// assert_eq!(result, Err(UnsortedError { index: 3 }));
+    /// ```
+    fn check_sorted_by<T, V, F>(self, compare: F) -> CheckSorted<Self, V, F>
+    where
+        Self: Iterator<Item = (T, V)> + Sized,
+        V: Clone,
+        F: FnMut(&V, &V) -> core::cmp::Ordering,
+    {
+        CheckSorted::new(self, compare)
+    }
+
+    /// Creates an iterator which yields the same indexed values as the source iterator, wrapped in `Ok`,
+    /// while they're sorted according to the key extracted by `key`.
+    ///
+    /// The source iterator must yield pairs `(i, val)`, like the indexed iterators of this crate. When
+    /// the key of `val` is smaller than the key of the previous value, the iterator yields an
+    /// [`UnsortedError`] holding the index of `val` instead of the pair, then `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::{IndexerIterator, UnsortedError};
+    ///
+    /// let records = vec![("a", 10), ("b", 20), ("c", 20), ("d", 15)];
+    /// let mut result = records.into_iter().index::<u32>().check_sorted_by_key(|r| r.1);
+    ///
+    /// assert_eq!(result.nth(2), Some(Ok((2, ("c", 20)))));
+    /// assert_eq!(result.next(), Some(Err(UnsortedError { index: 3 })));
+    /// assert_eq!(result.next(), None);
+    /// ```
+    fn check_sorted_by_key<T, V, K, F>(self, key: F) -> CheckSortedByKey<Self, K, F>
+    where
+        Self: Iterator<Item = (T, V)> + Sized,
+        K: Ord,
+        F: FnMut(&V) -> K,
+    {
+        CheckSortedByKey::new(self, key)
+    }
+
+    /// Creates an iterator which traverses a nested structure depth-first, giving the path of each leaf
+    /// as well as the leaf itself.
+    ///
+    /// The values of the source iterator must implement [`Nested`], which tells whether a node is a
+    /// container or a leaf, so the nesting can be arbitrarily deep. The iterator yields pairs
+    /// `(path, leaf)`, where `path` is an [`IndexPath`] holding the index at each level, from the top
+    /// level down to the level of `leaf`. Each level starts counting at 0 and increments by 1.
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::{IndexerIterator, Nested};
+    ///
+    /// enum Tree { Leaf(char), Node(Vec<Tree>) }
+    ///
+    /// impl Nested for Tree {
+    ///     type Children = std::vec::IntoIter<Tree>;
+    ///
+    ///     fn into_children(self) -> Result<Self::Children, Self> {
+    ///         match self {
+    ///             Tree::Node(children) => Ok(children.into_iter()),
+    ///             leaf => Err(leaf),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let forest = vec![Tree::Leaf('a'), Tree::Node(vec![Tree::Leaf('b'), Tree::Node(vec![Tree::Leaf('c')])])];
+    /// let result = forest.into_iter()
+    ///     .path_indexed::<u8>()
+    ///     .map(|(path, leaf)| match leaf { Tree::Leaf(c) => (path.to_vec(), c), _ => unreachable!() })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![(vec![0], 'a'), (vec![1, 0], 'b'), (vec![1, 1, 0], 'c')]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn path_indexed<T>(self) -> PathIndexer<Self, Self::Item, T>
+    where
+        Self: Sized,
+        Self::Item: Nested,
+        u8: Into<T>,
+        T: Clone,
+    {
+        PathIndexer::new(self, 0.into(), 1.into())
+    }
+
+    /// Creates an iterator which gives the preorder number and the depth of each node of a depth-first
+    /// tree traversal, as well as the node itself.
+    ///
+    /// The source iterator must yield the nodes in preorder, like the walkers of a file system or of an
+    /// abstract syntax tree, and the `depth` closure gives the depth of each node, 0 being the root level.
+    /// The iterator yields pairs `((n, depth), node)`, where `n` is of type `T`, starts at 0 and increments
+    /// by 1, so it's a stable number for the node within the traversal.
+    ///
+    /// See also [`index_outline`](IndexerIterator::index_outline) for a hierarchical label of the nodes.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let walk = vec![(0, "/"), (1, "bin"), (2, "ls"), (1, "etc")];
+    /// let result = walk.into_iter()
+    ///     .index_preorder::<u32, _>(|(depth, _)| *depth)
+    ///     .map(|(n, (_, name))| (n, name))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![((0, 0), "/"), ((1, 1), "bin"), ((2, 2), "ls"), ((3, 1), "etc")]);
+    /// ```
+    fn index_preorder<T, F>(self, depth: F) -> PreorderIndexer<Self, T, F>
+    where
+        Self: Sized,
+        u8: Into<T>,
+        F: FnMut(&Self::Item) -> usize,
+    {
+        PreorderIndexer::new(self, 0.into(), 1.into(), depth)
+    }
+
+    /// Creates an iterator which groups the indexed values of the source iterator in chunks of `N` values,
+    /// and which yields the result of `f(&indices, &values)` for each chunk, where `indices` and `values`
+    /// are arrays of `N` elements.
+    ///
+    /// The source iterator must yield pairs `(i, val)`, like the indexed iterators of this crate. Working
+    /// on fixed-size arrays allows the compiler to vectorize the loops in the closure. If the number of
+    /// values isn't a multiple of `N`, the last values are left in [`IndexedChunks::remainder`] when the
+    /// iteration is over.
+    ///
+    /// `N` can be given explicitly or inferred from the type of the closure arguments.
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let samples = vec![1.0_f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+    /// let mut chunks = samples.into_iter()
+    ///     .index::<u32>()
+    ///     .map_indexed_chunks(|i: &[u32; 4], x: &[f32; 4]| {
+    ///         let mut sum = 0.0;
+    ///         for k in 0..4 {
+    ///             sum += i[k] as f32 * x[k];
+    ///         }
+    ///         sum
+    ///     });
+    ///
+    /// assert_eq!(chunks.next(), Some(20.0));
+    /// assert_eq!(chunks.next(), None);
+    /// assert_eq!(chunks.remainder(), (&[4, 5, 6][..], &[5.0, 6.0, 7.0][..]));
+    /// ```
+    #[cfg(feature = "std")]
+    fn map_indexed_chunks<const N: usize, T, V, R, F>(self, f: F) -> IndexedChunks<Self, T, V, F, N>
+    where
+        Self: Iterator<Item = (T, V)> + Sized,
+        F: FnMut(&[T; N], &[V; N]) -> R,
+    {
+        IndexedChunks::new(self, f)
+    }
+
+    /// Creates an iterator over key-value pairs which gives an index of each pair as well as the key
+    /// and the value, as a flat triple.
+    ///
+    /// The iterator yields triples `(i, key, value)` instead of `(i, (key, value))`, which is easier to
+    /// destructure in a `for` loop over the entries of a map. `i` starts counting at 0 and increments by 1.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let scores = BTreeMap::from([("alice", 12), ("bob", 9)]);
+    /// let mut lines = Vec::new();
+    /// for (i, name, score) in scores.iter().index_entries::<u32>() {
+    ///     lines.push(format!("{i}. {name}: {score}"));
+    /// }
+    ///
+    /// assert_eq!(lines, vec!["0. alice: 12", "1. bob: 9"]);
+    /// ```
+    fn index_entries<T>(self) -> EntryIndexer<Self, T> where Self: Sized, u8: Into<T> {
+        EntryIndexer::new(self, 0.into(), 1.into())
+    }
+
+    /// Creates the sending side of a fan-out, which distributes the indexed values of the source iterator
+    /// round-robin to `n` bounded channels of capacity `capacity`, and the receiving ends of the channels.
+    ///
+    /// The source iterator must yield pairs `(i, val)`, like the indexed iterators of this crate, so the
+    /// workers receive the values already labelled with their original position. The values are sent when
+    /// [`FanOut::run`] is called, typically in a producer thread.
+    ///
+    /// This method requires the `crossbeam` feature.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let (fan_out, receivers) = (0..10).index::<u32>().fan_out(3, 4);
+    /// let sums = thread::scope(|s| {
+    ///     let workers = receivers.into_iter()
+    ///         .map(|rx| s.spawn(move || rx.iter().map(|(i, x)| i * x).sum::<u32>()))
+    ///         .collect::<Vec<_>>();
+    ///     fan_out.run().unwrap();
+    ///     workers.into_iter().map(|w| w.join().unwrap()).collect::<Vec<_>>()
+    /// });
+    ///
+    /// assert_eq!(sums.iter().sum::<u32>(), (0..10).map(|x| x * x).sum());
+    /// ```
+    #[cfg(feature = "crossbeam")]
+    fn fan_out<T, V>(self, n: usize, capacity: usize) -> (FanOut<Self, RoundRobin<T, V>>, Receivers<T, V>)
+    where
+        Self: Iterator<Item = (T, V)> + Sized,
+    {
+        FanOut::new(self, n, capacity, None)
+    }
+
+    /// Creates the sending side of a fan-out, which distributes the indexed values of the source iterator
+    /// to `n` bounded channels of capacity `capacity`, and the receiving ends of the channels.
+    ///
+    /// Each pair `(i, val)` is sent to the channel `shard(&i, &val)`, which must be smaller than `n`. See
+    /// [`fan_out`](IndexerIterator::fan_out) for more details.
+    ///
+    /// This method requires the `crossbeam` feature.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let words = vec!["apple", "kiwi", "avocado"];
+    /// let (fan_out, receivers) = words.into_iter()
+    ///     .index::<u8>()
+    ///     .fan_out_by(2, 10, |_, w| usize::from(!w.starts_with('a')));
+    /// fan_out.run().unwrap();
+    ///
+    /// assert_eq!(receivers[0].iter().collect::<Vec<_>>(), vec![(0, "apple"), (2, "avocado")]);
+    /// assert_eq!(receivers[1].iter().collect::<Vec<_>>(), vec![(1, "kiwi")]);
+    /// ```
+    #[cfg(feature = "crossbeam")]
+    fn fan_out_by<T, V, F>(self, n: usize, capacity: usize, shard: F) -> (FanOut<Self, F>, Receivers<T, V>)
+    where
+        Self: Iterator<Item = (T, V)> + Sized,
+        F: FnMut(&T, &V) -> usize,
+    {
+        FanOut::new(self, n, capacity, Some(shard))
+    }
+
+    /// Creates an iterator which takes the indexed values of the source iterator in any order, and yields
+    /// them in the order of their index, wrapped in `Ok`.
+    ///
+    /// The source iterator must yield pairs `(i, val)` whose indices, once sorted, are 0, 1, 2, ...
+    /// without gap, like the results of workers processing the values of an indexed iterator in parallel.
+    /// For indices with another start or step, use [`reorder_by_index_step`](IndexerIterator::reorder_by_index_step).
+    /// The values which arrive before their turn are kept in a buffer of `capacity` values.
+    ///
+    /// The iterator yields a [`ReorderError`] then `None` if the next index is still missing when the
+    /// buffer is full or when the source iterator ends, or if an index is received twice.
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let results = vec![(1_u32, "b"), (0, "a"), (3, "d"), (2, "c")];
+    /// let ordered = results.into_iter().reorder_by_index(2).collect::<Result<Vec<_>, _>>();
+    ///
+    /// assert_eq!(ordered, Ok(vec![(0, "a"), (1, "b"), (2, "c"), (3, "d")]));
+    /// ```
+    #[cfg(feature = "std")]
+    fn reorder_by_index<T, V>(self, capacity: usize) -> Reorder<Self, T, V>
+    where
+        Self: Iterator<Item = (T, V)> + Sized,
+        u8: Into<T>,
+        T: Ord,
+    {
+        Reorder::new(self, 0.into(), 1.into(), capacity)
+    }
+
+    /// Creates an iterator which takes the indexed values of the source iterator in any order, and yields
+    /// them in the order of their index, wrapped in `Ok`, like [`reorder_by_index`](IndexerIterator::reorder_by_index)
+    /// but for indices which start at `start` and are incremented by `step`.
+    ///
+    /// The source iterator must yield pairs `(i, val)` whose indices, once sorted, are `start`, `start + step`,
+    /// `start + 2 * step`, ... without gap, as given by [`index_step`](IndexerIterator::index_step). `step`
+    /// must be positive.
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let results = vec![(30_u32, "b"), (10, "a"), (50, "c")];
+    /// let ordered = results.into_iter().reorder_by_index_step(10, 20, 2).collect::<Result<Vec<_>, _>>();
+    ///
+    /// assert_eq!(ordered, Ok(vec![(10, "a"), (30, "b"), (50, "c")]));
+    /// ```
+    #[cfg(feature = "std")]
+    fn reorder_by_index_step<T, V>(self, start: T, step: T, capacity: usize) -> Reorder<Self, T, V>
+    where
+        Self: Iterator<Item = (T, V)> + Sized,
+        T: Ord,
+    {
+        Reorder::new(self, start, step, capacity)
+    }
+
+    /// Creates an iterator which gives the number of the current group of consecutive values with the same
+    /// key, as well as the value itself.
+    ///
+    /// The `key` closure gives the key of each value. The index starts at 0 and increments by 1 each time the
+    /// key differs from the key of the previous value, so all the values of a run share the same number, like
+    /// a dense rank over the consecutive runs.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let events = vec![("mon", 3), ("mon", 1), ("tue", 4), ("wed", 1), ("wed", 5)];
+    /// let result = events.into_iter()
+    ///     .index_group_number::<u32, _, _>(|(day, _)| *day)
+    ///     .map(|(g, (_, n))| (g, n))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![(0, 3), (0, 1), (1, 4), (2, 1), (2, 5)]);
+    /// ```
+    fn index_group_number<T, K, F>(self, key: F) -> GroupIndexer<Self, T, K, F>
+    where
+        Self: Sized,
+        u8: Into<T>,
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        GroupIndexer::new(self, 0.into(), 1.into(), key)
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value and the time it took to get it,
+    /// as well as the value itself.
+    ///
+    /// The iterator yields pairs `((i, elapsed), val)`, where `i` starts at 0 and increments by 1, and
+    /// `elapsed` is the time elapsed, measured with a monotonic clock, since the previous value was
+    /// yielded; for the first value, it's the time elapsed since it was requested. This allows to profile
+    /// the latency of a producer, like a channel or a decoder, along with the position of each value.
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let frames = (0..3).map(|n| { std::thread::sleep(Duration::from_millis(2)); n });
+    /// for ((i, elapsed), _frame) in frames.index_elapsed::<u32>() {
+    ///     assert!(elapsed >= Duration::from_millis(2));
+    ///     println!("frame {i} decoded in {elapsed:?}");
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    fn index_elapsed<T>(self) -> ElapsedIndexer<Self, T> where Self: Sized, u8: Into<T> {
+        ElapsedIndexer::new(self, 0.into(), 1.into())
+    }
+
+    /// Creates an iterator over the bits of a byte iterator, which gives the absolute position of each bit
+    /// as well as the bit itself.
+    ///
+    /// The iterator yields pairs `(i, bit)`, where `i` is of type `T` and starts at 0 and increments by 1,
+    /// and `bit` is a `bool`. The bits of each byte are read in the given `order`. This is useful for the
+    /// parsers of protocols and codecs, which need the position of the bits to report errors.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::{BitOrder, IndexerIterator};
+    ///
+    /// let data = vec![0b1000_0001_u8, 0b0100_0000];
+    /// let set_bits = data.into_iter()
+    ///     .index_bits::<u32>(BitOrder::MsbFirst)
+    ///     .filter_map(|(i, bit)| bit.then(|| i))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(set_bits, vec![0, 7, 9]);
+    /// ```
+    fn index_bits<T>(self, order: BitOrder) -> BitIndexer<Self, T>
+    where
+        Self: Iterator<Item = u8> + Sized,
+        u8: Into<T>,
+    {
+        BitIndexer::new(self, order, 0.into(), 1.into())
+    }
+
+    /// Creates an iterator over a flat row-major buffer of the given `width`, which gives the coordinates
+    /// of the tile containing each value, as well as the value itself.
+    ///
+    /// The buffer is split into tiles of `tile_w` × `tile_h` items. The iterator yields pairs
+    /// `((tile_row, tile_col, offset), val)`, where `tile_row` and `tile_col` are the row and column of the
+    /// tile, and `offset` is the row-major position of the value in the tile. If `width` isn't a multiple
+    /// of `tile_w`, the last tile of each row is narrower, but the offsets are still based on `tile_w`.
+    ///
+    /// The coordinates are updated incrementally, without any division per item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width`, `tile_w` or `tile_h` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// // 4 x 4 image split into 2 x 2 tiles
+    /// let pixels = 0..16;
+    /// let mut tiles = vec![[0; 4]; 4];
+    /// for ((tile_row, tile_col, offset), p) in pixels.index_tiled(4, 2, 2) {
+    ///     tiles[tile_row * 2 + tile_col][offset] = p;
+    /// }
+    ///
+    /// assert_eq!(tiles, vec![[0, 1, 4, 5], [2, 3, 6, 7], [8, 9, 12, 13], [10, 11, 14, 15]]);
+    /// ```
+    fn index_tiled(self, width: usize, tile_w: usize, tile_h: usize) -> TiledIndexer<Self> where Self: Sized {
+        TiledIndexer::new(self, width, tile_w, tile_h)
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself,
+    /// or `None` instead of the index once it has overflowed the type `T`.
+    ///
+    /// The iterator yields pairs `(Some(i), val)`, where `i` is of type `T` and starts at 0 and increments
+    /// by 1, until the next index can't be represented by `T`; the following pairs are `(None, val)`.
+    /// The overflow is never silent, unlike the other methods, even in release mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let result = (0..300).index_checked::<u8>().map(|(i, _)| i).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result[255], Some(255));
+    /// assert_eq!(result[256], None);
+    /// assert_eq!(result[299], None);
+    /// ```
+    fn index_checked<T>(self) -> CheckedIndexer<Self, T> where Self: Sized, u8: Into<T> {
+        CheckedIndexer::new(self, 0.into(), 1.into())
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself,
+    /// the index being clamped at the numeric bounds of the type `T` instead of overflowing.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and starts at `start` and increments
+    /// by `step` until it reaches `T::MAX`, or `T::MIN` if `step` is negative, after which it keeps that
+    /// value. This is useful when the index is only displayed, and the tail of a long stream can share
+    /// the same index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let result = (0..5).index_saturating::<u8>(250, 2).map(|(i, _)| i).collect::<Vec<_>>();
+    /// assert_eq!(result, vec![250, 252, 254, 255, 255]);
+    ///
+    /// let result = (0..4).index_saturating::<i16>(-32766, -1).map(|(i, _)| i).collect::<Vec<_>>();
+    /// assert_eq!(result, vec![-32766, -32767, -32768, -32768]);
+    /// ```
+    fn index_saturating<T>(self, start: T, step: T) -> SaturatingIndexer<Self, T> where Self: Sized {
+        SaturatingIndexer::new(self, start, step)
+    }
+
+    /// Creates an iterator which gives a decreasing index of the source iterator value as well as the value
+    /// itself.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and starts at `start` and decrements
+    /// by 1. The index is decremented by subtraction, so unsigned types can count down; the index can reach
+    /// 0 without underflowing.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against underflows, so the source iterator must not yield more than
+    /// `start + 1` items if `T` is unsigned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let items = vec!["a", "b", "c"];
+    /// let result = items.into_iter().index_desc::<u8>(2).collect::<Vec<_>>();
+    /// assert_eq!(result, vec![(2, "a"), (1, "b"), (0, "c")]);
+    /// ```
+    fn index_desc<T>(self, start: T) -> DescIndexer<Self, T> where Self: Sized, u8: Into<T> {
+        DescIndexer::new(self, start, 1.into())
+    }
+
+    /// Creates an iterator which gives a decreasing index of the source iterator value as well as the value
+    /// itself.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and starts at `start` and decrements
+    /// by `step`. The index is decremented by subtraction, so unsigned types can count down.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against underflows, so you may have to prevent it, depending on the type
+    /// `T` and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let result = (0..4).index_desc_step::<u32>(30, 10).collect::<Vec<_>>();
+    /// assert_eq!(result, vec![(30, 0), (20, 1), (10, 2), (0, 3)]);
+    /// ```
+    fn index_desc_step<T>(self, start: T, step: T) -> DescIndexer<Self, T> where Self: Sized {
+        DescIndexer::new(self, start, step)
+    }
+
+    /// Creates an iterator which gives the distance of the source iterator value from the end, as well
+    /// as the value itself.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and is the number of values after
+    /// `val`, so the last value gets 0. The index is computed from the length of the source iterator,
+    /// which must implement `ExactSizeIterator`, so the values don't need to be collected first.
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if an index can't be converted to `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let tasks = vec!["fetch", "build", "test"];
+    /// let result = tasks.into_iter().index_from_end::<u32>().collect::<Vec<_>>();
+    /// assert_eq!(result, vec![(2, "fetch"), (1, "build"), (0, "test")]);
+    /// ```
+    fn index_from_end<T>(self) -> FromEndIndexer<Self, T> where Self: ExactSizeIterator + Sized {
+        FromEndIndexer::new(self)
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself,
+    /// the index being of an unsigned type `T` and the step of a signed type `D`.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` starts at `start` and is incremented by `step`,
+    /// which can be negative. The index is only moved when the next value comes, so it can count down to
+    /// 0 without underflowing.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let result = (0..4).index_step_signed::<u32, i32>(30, -10).collect::<Vec<_>>();
+    /// assert_eq!(result, vec![(30, 0), (20, 1), (10, 2), (0, 3)]);
+    /// ```
+    fn index_step_signed<T, D>(self, start: T, step: D) -> SignedStepIndexer<Self, T, D>
+    where
+        Self: Sized,
+        T: SignedStep<D>,
+    {
+        SignedStepIndexer::new(self, start, step)
+    }
+
+    /// Creates an iterator which gives a geometric index of the source iterator value as well as the value
+    /// itself.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and starts at `start` and is multiplied
+    /// by `factor` at each value, like power-of-two bucket labels. The index is only multiplied when the
+    /// next value comes, so the last index can be the largest value that fits in `T`.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let buckets = vec![12, 7, 3, 0];
+    /// let result = buckets.into_iter().index_scale::<u32>(1, 2).collect::<Vec<_>>();
+    /// assert_eq!(result, vec![(1, 12), (2, 7), (4, 3), (8, 0)]);
+    /// ```
+    fn index_scale<T>(self, start: T, factor: T) -> ScaleIndexer<Self, T> where Self: Sized {
+        ScaleIndexer::new(self, start, factor)
+    }
+
+    /// Creates an iterator which gives an index generated by a closure for each source iterator value,
+    /// as well as the value itself.
+    ///
+    /// The iterator yields pairs `(f(), val)`. The closure is called once per value, in order, so any
+    /// index sequence can be produced without implementing the arithmetic traits on the index type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let mut ids = ["u1", "u2", "u3"].into_iter();
+    /// let result = "abc".chars().index_with(|| ids.next().unwrap()).collect::<Vec<_>>();
+    /// assert_eq!(result, vec![("u1", 'a'), ("u2", 'b'), ("u3", 'c')]);
+    /// ```
+    fn index_with<T, F>(self, f: F) -> IndexWith<Self, F>
+    where
+        Self: Sized,
+        F: FnMut() -> T,
+    {
+        IndexWith::new(self, f)
+    }
+
+    /// Creates an iterator which gives an index computed from the previous one for each source iterator
+    /// value, as well as the value itself.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is `first` for the first value, then `succ(&i)`
+    /// of the previous index. The closure is only called when the next value comes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// // Fibonacci indices
+    /// let result = (0..6).index_successors((1, 1), |&(a, b)| (b, a + b)).map(|((i, _), _)| i).collect::<Vec<_>>();
+    /// assert_eq!(result, vec![1, 1, 2, 3, 5, 8]);
+    /// ```
+    fn index_successors<T, F>(self, first: T, succ: F) -> IndexSuccessors<Self, T, F>
+    where
+        Self: Sized,
+        F: FnMut(&T) -> T,
+    {
+        IndexSuccessors::new(self, first, succ)
+    }
+
+    /// Creates an iterator which gives the next value of the `indices` iterator for each source iterator
+    /// value, as well as the value itself.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is the next value of `indices`. When `indices` is
+    /// exhausted before the source iterator, the iterator behaves as given by `exhaustion`: it stops, panics,
+    /// or repeats the last index.
+    ///
+    /// # Panics
+    ///
+    /// With [`Exhaustion::Panic`], the iterator panics if `indices` is exhausted before the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::{Exhaustion, IndexerIterator};
+    ///
+    /// let ids = vec![104, 107, 110];
+    /// let result = "abcd".chars().index_by(ids.iter().copied(), Exhaustion::Stop).collect::<Vec<_>>();
+    /// assert_eq!(result, vec![(104, 'a'), (107, 'b'), (110, 'c')]);
+    ///
+    /// let result = "abcd".chars().index_by(ids.into_iter(), Exhaustion::RepeatLast).collect::<Vec<_>>();
+    /// assert_eq!(result, vec![(104, 'a'), (107, 'b'), (110, 'c'), (110, 'd')]);
+    /// ```
+    fn index_by<J>(self, indices: J, exhaustion: Exhaustion) -> IndexBy<Self, J::IntoIter>
+    where
+        Self: Sized,
+        J: IntoIterator,
+        J::Item: Clone,
+    {
+        IndexBy::new(self, indices.into_iter(), exhaustion)
+    }
+
+    /// Creates an iterator which gives an alphabetic label for each value of the source iterator, as well
+    /// as the value itself.
+    ///
+    /// The iterator yields pairs `(label, val)`, where `label` is an [`Alpha`] in bijective base 26 which
+    /// starts at `start` and is displayed like `a`, `b`, ..., `z`, `aa`, `ab`, ... The case of the labels
+    /// is the case of `start`, so `"A"` gives uppercase labels.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `start` isn't a valid label; see [`Alpha::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let figures = (0..28).index_alpha("a").map(|(label, _)| label.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(figures[..3], ["a", "b", "c"]);
+    /// assert_eq!(figures[25..], ["z", "aa", "ab"]);
+    ///
+    /// let result = ["x", "y"].iter().index_alpha("C").map(|(label, _)| label.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(result, ["C", "D"]);
+    /// ```
+    fn index_alpha(self, start: &str) -> AlphaIndexer<Self> where Self: Sized {
+        let start = Alpha::parse(start).unwrap_or_else(|| panic!("invalid alphabetic label: {start:?}"));
+        AlphaIndexer::new(self, start)
+    }
+
+    /// Creates an iterator which gives a Roman numeral for each value of the source iterator, as well
+    /// as the value itself.
+    ///
+    /// The iterator yields pairs `(numeral, val)`, where `numeral` is a [`Roman`] which starts at 1 and
+    /// is displayed like `I`, `II`, `III`, `IV`, ..., or `i`, `ii`, `iii`, `iv`, ... depending on `case`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::{IndexerIterator, LetterCase};
+    ///
+    /// let articles = vec!["Definitions", "Scope", "Term", "Termination"];
+    /// let outline = articles.into_iter()
+    ///     .index_roman(LetterCase::Upper)
+    ///     .map(|(n, title)| format!("Article {n}. {title}"))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(outline[3], "Article IV. Termination");
+    /// ```
+    fn index_roman(self, case: LetterCase) -> RomanIndexer<Self> where Self: Sized {
+        RomanIndexer::new(self, case)
+    }
+
+    /// Creates an iterator which gives an English ordinal label for each value of the source iterator,
+    /// as well as the value itself.
+    ///
+    /// The iterator yields pairs `(ordinal, val)`, where `ordinal` is an [`Ordinal`] which starts at
+    /// `start` and increments by 1, and which is displayed like `1st`, `2nd`, `3rd`, `4th`, ..., `11th`,
+    /// `12th`, `13th`, ..., `21st`, ...
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let runners = vec!["Ann", "Bob", "Cid", "Dee"];
+    /// let result = runners.into_iter()
+    ///     .index_ordinal(1)
+    ///     .map(|(place, name)| format!("{place}: {name}"))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec!["1st: Ann", "2nd: Bob", "3rd: Cid", "4th: Dee"]);
+    /// ```
+    fn index_ordinal(self, start: usize) -> OrdinalIndexer<Self> where Self: Sized {
+        OrdinalIndexer::new(self, start)
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value formatted as a string, as well
+    /// as the value itself.
+    ///
+    /// The iterator yields pairs `(fmt(&i), val)`, where `i` is of type `T` and starts at `start` and
+    /// increments by `step`. Since the length of the source iterator is known before the closure is
+    /// created, the closure can pad the indices to the width of the largest one.
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let frames = vec!["f0", "f1", "f2"];
+    /// let result = frames.iter()
+    ///     .index_format(10_u32, 10, |i| format!("frame_{i:04}.png"))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result[2], ("frame_0030.png".to_string(), &"f2"));
+    ///
+    /// let width = (frames.len() - 1).to_string().len() + 1;
+    /// let result = frames.iter().index_format(0_u8, 1, |i| format!("{i:0width$}")).collect::<Vec<_>>();
+    /// assert_eq!(result[1].0, "01");
+    /// ```
+    #[cfg(feature = "std")]
+    fn index_format<T, F>(self, start: T, step: T, fmt: F) -> FormatIndexer<Self, T, F>
+    where
+        Self: Sized,
+        F: FnMut(&T) -> String,
+    {
+        FormatIndexer::new(self, start, step, fmt)
+    }
+
+    /// Creates an iterator which gives an index label in the given `radix` for each value of the source
+    /// iterator, as well as the value itself.
+    ///
+    /// The iterator yields pairs `(label, val)`, where `label` is a [`RadixLabel`] whose value is of type `T`
+    /// and starts at 0 and increments by 1, and which is displayed with at least `width` digits, like
+    /// `0x00A3` in hexadecimal. The radices 2, 8 and 16 are prefixed by `0b`, `0o` and `0x`.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `radix` isn't between 2 and 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let memory = [0x4c_u8, 0x6f, 0x72, 0x65, 0x6d];
+    /// let dump = memory.chunks(2)
+    ///     .index_radix::<usize>(16, 4)
+    ///     .map(|(chunk, bytes)| format!("{chunk}: {bytes:02x?}"))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(dump, vec!["0x0000: [4c, 6f]", "0x0001: [72, 65]", "0x0002: [6d]"]);
+    /// ```
+    fn index_radix<T>(self, radix: u32, width: usize) -> RadixIndexer<Self, T> where Self: Sized, u8: Into<T> {
+        RadixIndexer::new(self, 0.into(), 1.into(), radix, width)
+    }
+
+    /// Creates an iterator which gives a zero-padded decimal index label for each value of the source
+    /// iterator, as well as the value itself.
+    ///
+    /// The iterator yields pairs `(label, val)`, where `label` is a [`Padded`] whose value starts at `start`
+    /// and increments by 1, and which is padded with zeros to the width of the largest index. That width is
+    /// computed from the length of the source iterator, so names generated from the labels sort correctly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let pages = vec!["cover"; 120];
+    /// let names = pages.iter()
+    ///     .index_padded(1)
+    ///     .map(|(n, _)| format!("page_{n}.png"))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(names[6], "page_007.png");
+    /// assert_eq!(names[119], "page_120.png");
+    /// ```
+    fn index_padded(self, start: usize) -> PaddedIndexer<Self> where Self: ExactSizeIterator + Sized {
+        PaddedIndexer::new(self, start)
+    }
+
+    /// Creates an iterator over a flat row-major grid of the given `width`, which gives the coordinates
+    /// of each value as well as the value itself.
+    ///
+    /// The iterator yields pairs `((row, col), val)`, where `row` and `col` are of type `T` and start at 0.
+    /// The coordinates are updated incrementally, without any division per item.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `width` is 0, and the iterator panics if a coordinate can't be converted to `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let grid = "#..#.#".chars();
+    /// let walls = grid.index_2d::<u16>(3)
+    ///     .filter_map(|(pos, c)| (c == '#').then_some(pos))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(walls, vec![(0, 0), (1, 0), (1, 2)]);
+    /// ```
+    fn index_2d<T>(self, width: usize) -> Index2d<Self, T> where Self: Sized {
+        Index2d::new(self, width)
+    }
+
+    /// Creates an iterator over a flat volume of the given `width` and `height`, which gives the coordinates
+    /// of each value as well as the value itself.
+    ///
+    /// The iterator yields pairs `((x, y, z), val)`, where `x`, `y` and `z` are of type `T` and start at 0,
+    /// `x` varying the fastest, so the position of a value in the buffer is `x + width * (y + height * z)`.
+    /// The coordinates are updated incrementally, without any division per item.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `width` or `height` is 0, and the iterator panics if a coordinate can't be
+    /// converted to `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// // 2 x 2 x 2 voxels
+    /// let density = [0.0, 0.1, 0.0, 0.0, 0.0, 0.0, 0.9, 0.0];
+    /// let dense = density.iter()
+    ///     .index_3d::<u32>(2, 2)
+    ///     .filter_map(|(pos, &d)| (d > 0.5).then_some(pos))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(dense, vec![(0, 1, 1)]);
+    /// ```
+    fn index_3d<T>(self, width: usize, height: usize) -> Index3d<Self, T> where Self: Sized {
+        Index3d::new(self, width, height)
+    }
+
+    /// Creates an iterator over a flat N-dimensional array of the given `shape`, which gives the coordinates
+    /// of each value as well as the value itself.
+    ///
+    /// The iterator yields pairs `(coords, val)`, where `coords` is an array of `N` coordinates of type `T`
+    /// starting at 0, in row-major order: the last coordinate varies the fastest, like the default layout
+    /// of NumPy or `ndarray`. The first coordinate isn't bounded by `shape[0]`. The coordinates are updated
+    /// incrementally, without any division per item.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `N` or a dimension of `shape` is 0, and the iterator panics if a coordinate
+    /// can't be converted to `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let tensor = (0..24).map(|x| x as f32);
+    /// let coords = tensor.index_nd::<u8, 3>([2, 3, 4])
+    ///     .find(|&(_, x)| x == 17.0)
+    ///     .map(|(c, _)| c);
+    ///
+    /// assert_eq!(coords, Some([1, 1, 1]));
+    /// ```
+    fn index_nd<T, const N: usize>(self, shape: [usize; N]) -> IndexNd<Self, T, N> where Self: Sized {
+        IndexNd::new(self, shape)
+    }
+
+    /// Creates an iterator which gives the chunk number and the offset in the chunk of each source iterator
+    /// value, as well as the value itself.
+    ///
+    /// The iterator yields pairs `((chunk, offset), val)`, where `chunk` and `offset` are of type `T` and
+    /// start at 0, and `offset` goes back to 0 every `chunk_size` values. This is the natural index of
+    /// fixed-size records or blocks. The index is updated incrementally, without any division per item.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `chunk_size` is 0, and the iterator panics if an index can't be converted to `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let bytes = b"ID01alphaID02beta_".iter();
+    /// let ids = bytes
+    ///     .index_chunked::<u32>(9)
+    ///     .filter(|&((_, offset), _)| offset == 3)
+    ///     .map(|((record, _), &b)| (record, b as char))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(ids, vec![(0, '1'), (1, '2')]);
+    /// ```
+    fn index_chunked<T>(self, chunk_size: usize) -> Index2d<Self, T> where Self: Sized {
+        Index2d::new(self, chunk_size)
+    }
+
+    /// Creates an iterator which gives the page number and the position on the page of each source iterator
+    /// value, as well as the value itself.
+    ///
+    /// The iterator yields pairs `((page, position), val)`, where `page` is of type `T` and starts at
+    /// `first_page`, and `position` is of type `T` and starts at 0 on each page of `page_size` values.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `page_size` is 0, and the iterator panics if a position can't be converted to `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let results = vec!["r1", "r2", "r3", "r4", "r5"];
+    /// let result = results.into_iter().index_paged::<u32>(2, 1).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![((1, 0), "r1"), ((1, 1), "r2"), ((2, 0), "r3"), ((2, 1), "r4"), ((3, 0), "r5")]);
+    /// ```
+    fn index_paged<T>(self, page_size: usize, first_page: T) -> PagedIndexer<Self, T> where Self: Sized {
+        PagedIndexer::new(self, page_size, first_page)
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself,
+    /// restarting the index at `start` each time `predicate` returns `true` for an item.
+    ///
+    /// This is the same as [`index_reset_when`](IndexerIterator::index_reset_when), with a custom start
+    /// value and step: the item for which the predicate matches gets the index `start`, and the index of
+    /// the following items increments by `step` until the next match.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let lines = vec!["", "one", "two", "", "three"];
+    /// let result = lines.into_iter()
+    ///     .index_step_reset_when::<u32, _>(0, 10, |s| s.is_empty())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![(0, ""), (10, "one"), (20, "two"), (0, ""), (10, "three")]);
+    /// ```
+    fn index_step_reset_when<T, P>(self, start: T, step: T, predicate: P) -> ResetIndexer<Self, T, P>
+    where
+        Self: Sized,
+        T: Clone,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        ResetIndexer::new(self, start, step, predicate)
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself,
+    /// each group of items having its own independent count.
+    ///
+    /// The `key` closure gives the group of each item. The iterator yields pairs `(i, val)`, where `i`
+    /// is the number of items of the same group that came before `val`. Unlike
+    /// [`index_group_number`](IndexerIterator::index_group_number), the items of a group don't need to
+    /// be consecutive.
+    ///
+    /// The counters are kept in a `HashMap`, so the memory grows with the number of distinct keys.
+    ///
+    /// This method requires the `std` feature.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let words = vec!["apple", "bean", "avocado", "banana", "cherry", "apricot"];
+    /// let result = words.into_iter()
+    ///     .index_by_group::<u32, _, _>(|w| w.chars().next())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![(0, "apple"), (0, "bean"), (1, "avocado"), (1, "banana"), (0, "cherry"), (2, "apricot")]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn index_by_group<T, K, F>(self, key: F) -> PerGroupIndexer<Self, T, K, F>
+    where
+        Self: Sized,
+        u8: Into<T>,
+        K: std::hash::Hash + Eq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        PerGroupIndexer::new(self, 0.into(), 1.into(), key)
+    }
+
+    /// Creates an iterator which gives an index of the source iterator values that match a predicate,
+    /// as well as the value itself.
+    ///
+    /// The iterator yields pairs `(Some(i), val)` for the values matching `predicate`, where `i` is of
+    /// type `T` and starts at 0 and increments by 1 at each matching value, and `(None, val)` for the
+    /// other values. Unlike `filter(predicate).index()`, the other values are kept in the sequence.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let log = vec!["start", "error: disk", "read", "error: net", "stop"];
+    /// let result = log.into_iter()
+    ///     .index_if::<u32, _>(|line| line.starts_with("error"))
+    ///     .map(|(i, line)| match i {
+    ///         Some(i) => format!("[{i}] {line}"),
+    ///         None => line.to_string(),
+    ///     })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec!["start", "[0] error: disk", "read", "[1] error: net", "stop"]);
+    /// ```
+    fn index_if<T, P>(self, predicate: P) -> ConditionalIndexer<Self, T, P>
+    where
+        Self: Sized,
+        u8: Into<T>,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        ConditionalIndexer::new(self, 0.into(), 1.into(), predicate)
+    }
+
+    /// Creates an iterator which gives a coarse index of the source iterator value as well as the value
+    /// itself, the index being incremented only once every `n` values.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and starts at 0 and increments
+    /// by 1 every `n` values, so each batch of `n` consecutive values shares the same index. The last
+    /// batch may be shorter than `n`.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let frames = vec!["f0", "f1", "f2", "f3", "f4", "f5", "f6"];
+    /// let result = frames.into_iter().index_every::<u32>(3).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![(0, "f0"), (0, "f1"), (0, "f2"), (1, "f3"), (1, "f4"), (1, "f5"), (2, "f6")]);
+    /// ```
+    fn index_every<T>(self, n: usize) -> EveryIndexer<Self, T> where Self: Sized, u8: Into<T> {
+        EveryIndexer::new(self, n, 0.into(), 1.into())
+    }
+
+    /// Creates an iterator which gives a modular index of the source iterator value as well as the value
+    /// itself, for example to assign the values to workers in a round-robin fashion or to give their
+    /// position in a ring buffer.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is of type `T`, starts at 0 and increments by 1
+    /// until `modulus - 1`, then starts again at 0. Unlike the wrapping of
+    /// [`with_overflow_policy`](Indexer::with_overflow_policy), the index wraps at the given modulus
+    /// instead of the boundary of the type, so it never overflows.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `modulus` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let tasks = vec!["a", "b", "c", "d", "e"];
+    /// let mut workers = vec![Vec::new(); 2];
+    /// for (w, task) in tasks.into_iter().index_mod::<usize>(2) {
+    ///     workers[w].push(task);
+    /// }
+    ///
+    /// assert_eq!(workers, vec![vec!["a", "c", "e"], vec!["b", "d"]]);
+    /// ```
+    fn index_mod<T>(self, modulus: T) -> ModIndexer<Self, T> where Self: Sized, T: PartialEq + From<u8> {
+        ModIndexer::new(self, modulus)
+    }
 
-impl<I, T> Indexer<I, T> {
-    pub fn new(iter: I, start: T, step: T) -> Indexer<I, T> {
-        Indexer { iter, counter: start, step  }
+    /// Creates an iterator over repeated passes of `cycle_len` values, typically made with `cycle()`,
+    /// which gives the cycle number and the position in the cycle of each value, as well as the value
+    /// itself.
+    ///
+    /// The iterator yields triplets `(cycle, position, val)`, where `cycle` and `position` are of type `T`
+    /// and start at 0, and `position` goes back to 0 every `cycle_len` values, so the repeated passes
+    /// over the same data can be told apart. When the source is made from an `ExactSizeIterator`,
+    /// `cycle_len` is simply its `len()` before calling `cycle()`.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `cycle_len` is 0, and the iterator panics if an index can't be converted to `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let steps = ["warm-up", "run", "cool-down"].iter();
+    /// let result = steps.clone().cycle()
+    ///     .index_cycles::<u32>(steps.len())
+    ///     .take(5)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![
+    ///     (0, 0, &"warm-up"), (0, 1, &"run"), (0, 2, &"cool-down"),
+    ///     (1, 0, &"warm-up"), (1, 1, &"run"),
+    /// ]);
+    /// ```
+    fn index_cycles<T>(self, cycle_len: usize) -> CycleIndexer<Self, T> where Self: Sized {
+        CycleIndexer::new(self, cycle_len)
     }
-}
 
-pub trait IndexerIterator {
-    /// Creates an iterator which gives an index of the source iterator value as well as the value itself.
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself,
+    /// like [`index`](IndexerIterator::index), but for any type `T` implementing the `num_traits::Zero`
+    /// and `num_traits::One` traits rather than `u8: Into<T>`.
     ///
-    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and contains the current
-    /// index of iteration, and `val` is the value returned by the source iterator.
+    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and starts at `T::zero()` and
+    /// increments by `T::one()`.
     ///
-    /// `index::<T>()` starts counting at 0 and increments by 1. If you need another start value, use
-    /// `index_start::<T>(start: T)` instead. If you need different steps than 1, use
-    /// `index_step::<T>(start: T, step: T)`.
+    /// This method requires the `num` feature.
     ///
     /// # Overflow Behavior
     ///
@@ -68,26 +2566,26 @@ pub trait IndexerIterator {
     ///
     /// ```
     /// use iter_index::IndexerIterator;
+    /// use std::num::Wrapping;
     ///
     /// let items = vec!["a", "b", "c"];
-    /// let mut result = items.into_iter().index::<i32>();
+    /// let result = items.into_iter().index_num::<Wrapping<u8>>().collect::<Vec<_>>();
     ///
-    /// assert_eq!(result.next(), Some((0_i32, "a")));
-    /// assert_eq!(result.next(), Some((1_i32, "b")));
-    /// assert_eq!(result.next(), Some((2_i32, "c")));
-    /// assert_eq!(result.next(), None);
+    /// assert_eq!(result, vec![(Wrapping(0), "a"), (Wrapping(1), "b"), (Wrapping(2), "c")]);
     /// ```
-    fn index<T>(self) -> Indexer<Self, T> where Self: Sized, u8: Into<T> {
-        Indexer::new(self, 0.into(), 1.into())
+    #[cfg(feature = "num")]
+    fn index_num<T>(self) -> Indexer<Self, T> where Self: Sized, T: num_traits::Zero + num_traits::One {
+        Indexer::new(self, T::zero(), T::one())
     }
 
-    /// Creates an iterator which gives an index of the source iterator value as well as the value itself.
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself,
+    /// like [`index_start`](IndexerIterator::index_start), but for any type `T` implementing the
+    /// `num_traits::One` trait rather than `u8: Into<T>`.
     ///
-    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and contains the current
-    /// index of iteration, and `val` is the value returned by the source iterator.
+    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and starts at `start` and
+    /// increments by `T::one()`.
     ///
-    /// `index_start::<T>(start: T)` starts counting at `start` and increments by 1. If you need different
-    /// steps than 1, use `index_step::<T>(start: T, step: T)`.
+    /// This method requires the `num` feature.
     ///
     /// # Overflow Behavior
     ///
@@ -98,25 +2596,210 @@ pub trait IndexerIterator {
     ///
     /// ```
     /// use iter_index::IndexerIterator;
+    /// use std::num::Wrapping;
     ///
     /// let items = vec!["a", "b", "c"];
-    /// let mut result = items.into_iter().index_start::<u8>(97);
+    /// let result = items.into_iter().index_num_start(Wrapping(255_u8)).collect::<Vec<_>>();
     ///
-    /// assert_eq!(result.next(), Some((97_u8, "a")));
-    /// assert_eq!(result.next(), Some((98_u8, "b")));
-    /// assert_eq!(result.next(), Some((99_u8, "c")));
-    /// assert_eq!(result.next(), None);
+    /// assert_eq!(result, vec![(Wrapping(255), "a"), (Wrapping(0), "b"), (Wrapping(1), "c")]);
     /// ```
-    fn index_start<T>(self, start: T) -> Indexer<Self, T> where Self: Sized, u8: Into<T> {
-        Indexer::new(self, start, 1.into())
+    #[cfg(feature = "num")]
+    fn index_num_start<T>(self, start: T) -> Indexer<Self, T> where Self: Sized, T: num_traits::One {
+        Indexer::new(self, start, T::one())
     }
 
-    /// Creates an iterator which gives an index of the source iterator value as well as the value itself.
+    /// Creates an iterator which gives a [`Duration`](core::time::Duration) index of the source iterator
+    /// value as well as the value itself, for example to timestamp the frames of an audio or video stream.
     ///
-    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and contains the current
-    /// index of iteration, and `val` is the value returned by the source iterator.
+    /// The iterator yields pairs `(t, val)`, where `t` starts at `start` and increments by `step`.
+    /// `Duration` can't be used with [`index_step`](IndexerIterator::index_step), because it can't be
+    /// added by reference.
     ///
-    /// `index_step::<T>(start: T, step: T)` starts counting at `start` and increments by `step`.
+    /// # Panics
+    ///
+    /// The iterator panics if the index overflows `Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let frames = vec!["f0", "f1", "f2"];
+    /// let result = frames.into_iter()
+    ///     .index_duration(Duration::ZERO, Duration::from_millis(20))
+    ///     .map(|(t, f)| format!("{f}@{}ms", t.as_millis()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec!["f0@0ms", "f1@20ms", "f2@40ms"]);
+    /// ```
+    fn index_duration(self, start: core::time::Duration, step: core::time::Duration) -> DurationIndexer<Self>
+    where
+        Self: Sized,
+    {
+        DurationIndexer::new(self, start, step)
+    }
+
+    /// Creates an iterator which gives a calendar date of the source iterator value as well as the value
+    /// itself, for example to attach daily timestamps to the outputs of a simulation.
+    ///
+    /// The iterator yields pairs `(date, val)`, where `date` is a `chrono::NaiveDate` which starts at
+    /// `start` and increments by `step`. Each date is calculated from `start`, so a step which isn't a
+    /// whole number of days, like 12 hours, gives the date of each item without accumulating rounding errors;
+    /// the fractional days are ignored, like in `NaiveDate::checked_add_signed`.
+    ///
+    /// This method requires the `chrono` feature.
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if a date is out of the range of `NaiveDate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, TimeDelta};
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let outputs = vec![1.5, 2.0, 2.5];
+    /// let start = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
+    /// let result = outputs.into_iter()
+    ///     .index_dates(start, TimeDelta::days(1))
+    ///     .map(|(date, v)| format!("{date}: {v}"))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec!["2024-02-28: 1.5", "2024-02-29: 2", "2024-03-01: 2.5"]);
+    /// ```
+    #[cfg(feature = "chrono")]
+    fn index_dates(self, start: chrono::NaiveDate, step: chrono::TimeDelta) -> DateIndexer<Self>
+    where
+        Self: Sized,
+    {
+        DateIndexer::new(self, start, step)
+    }
+
+    /// Creates an iterator which gives a date, or a date and time, of the `time` crate for the source
+    /// iterator value as well as the value itself.
+    ///
+    /// The iterator yields pairs `(date, val)`, where `date` is a `time::Date`, `time::PrimitiveDateTime`
+    /// or `time::OffsetDateTime`, which starts at `start` and increments by `step`. The step is either a
+    /// fixed duration or a number of calendar months; see [`CalendarStep`]. Each date is calculated from
+    /// `start`, so stepping by months from January 31 gives the last day of each shorter month without
+    /// drifting.
+    ///
+    /// This method requires the `time` feature.
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if a date is out of the range of the date type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time::{Date, Month};
+    /// use iter_index::{CalendarStep, IndexerIterator};
+    ///
+    /// let invoices = vec![120, 95, 130];
+    /// let start = Date::from_calendar_date(2024, Month::January, 31).unwrap();
+    /// let result = invoices.into_iter()
+    ///     .index_calendar(start, CalendarStep::Months(1))
+    ///     .map(|(date, amount)| format!("{date}: {amount}"))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec!["2024-01-31: 120", "2024-02-29: 95", "2024-03-31: 130"]);
+    /// ```
+    #[cfg(feature = "time")]
+    fn index_calendar<D>(self, start: D, step: CalendarStep) -> CalendarIndexer<Self, D>
+    where
+        Self: Sized,
+        D: CalendarIndex,
+    {
+        CalendarIndexer::new(self, start, step)
+    }
+
+    /// Creates an iterator which gives a non-zero index of the source iterator value as well as the value
+    /// itself, for ID schemes that reserve 0.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is of a non-zero unsigned type `T`, like
+    /// `NonZeroU32`, and starts at 1 and increments by 1.
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if an index overflows `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroU32;
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let items = vec!["a", "b", "c"];
+    /// let result = items.into_iter().index_nonzero::<NonZeroU32>().map(|(id, _)| id.get()).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![1, 2, 3]);
+    /// ```
+    fn index_nonzero<T: NonZeroIndex>(self) -> NonZeroIndexer<Self, T> where Self: Sized {
+        NonZeroIndexer::new(self, T::ONE, T::ONE)
+    }
+
+    /// Creates an iterator which gives a non-zero index of the source iterator value as well as the value
+    /// itself, with a custom start and step.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is of a non-zero unsigned type `T`, like
+    /// `NonZeroU32`, and starts at `start` and increments by `step`. Since both are non-zero, the
+    /// index can never be 0.
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if an index overflows `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroU16;
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let start = NonZeroU16::new(100).unwrap();
+    /// let step = NonZeroU16::new(10).unwrap();
+    /// let result = "abc".chars().index_nonzero_step(start, step).map(|(id, c)| (id.get(), c)).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![(100, 'a'), (110, 'b'), (120, 'c')]);
+    /// ```
+    fn index_nonzero_step<T: NonZeroIndex>(self, start: T, step: T) -> NonZeroIndexer<Self, T> where Self: Sized {
+        NonZeroIndexer::new(self, start, step)
+    }
+
+    /// Creates an iterator which gives a [`Wrapping`](core::num::Wrapping) index of the source iterator value
+    /// as well as the value itself, so the index wraps around at the boundary of `T` instead of overflowing.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is a `Wrapping<T>` which starts at 0 and increments
+    /// by 1. It's the same as `index_step(Wrapping(0), Wrapping(1))`, which can be used for other start and
+    /// step values. Likewise, the `Saturating<T>` wrapper of the standard library, which requires Rust 1.74,
+    /// can be given to [`index_step`](IndexerIterator::index_step) to clamp the index at the boundary of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::Wrapping;
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let result = (0..300).index_wrapping::<u8>().map(|(i, _)| i.0).skip(254).take(4).collect::<Vec<_>>();
+    /// assert_eq!(result, vec![254, 255, 0, 1]);
+    ///
+    /// let result = (0..3).index_step(Wrapping(i8::MAX), Wrapping(64)).map(|(i, _)| i.0).collect::<Vec<_>>();
+    /// assert_eq!(result, vec![127, -65, -1]);
+    /// ```
+    fn index_wrapping<T>(self) -> Indexer<Self, core::num::Wrapping<T>> where Self: Sized, u8: Into<T> {
+        Indexer::new(self, core::num::Wrapping(0.into()), core::num::Wrapping(1.into()))
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself,
+    /// the step being of another type than the index, like an `Instant` index with a `Duration` step, or a
+    /// `chrono::NaiveDate` index with a `chrono::TimeDelta` step.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and starts at `start` and increments
+    /// by `step`. Unlike [`index_step`](IndexerIterator::index_step), the index and the step are added
+    /// by value, since these types don't implement the operators by reference, so they're cloned at each
+    /// step; it's meant for `Copy` types.
     ///
     /// # Overflow Behavior
     ///
@@ -126,18 +2809,129 @@ pub trait IndexerIterator {
     /// # Examples
     ///
     /// ```
+    /// use std::time::{Duration, Instant};
     /// use iter_index::IndexerIterator;
     ///
-    /// let items = vec!["a", "b", "c"];
-    /// let mut result = items.into_iter().index_step::<u32>(100, 10);
+    /// let start = Instant::now();
+    /// let deadlines = vec!["a", "b", "c"].into_iter()
+    ///     .index_step_with(start, Duration::from_secs(5))
+    ///     .map(|(t, _)| t - start)
+    ///     .collect::<Vec<_>>();
     ///
-    /// assert_eq!(result.next(), Some((100_u32, "a")));
-    /// assert_eq!(result.next(), Some((110_u32, "b")));
-    /// assert_eq!(result.next(), Some((120_u32, "c")));
-    /// assert_eq!(result.next(), None);
+    /// assert_eq!(deadlines, vec![Duration::ZERO, Duration::from_secs(5), Duration::from_secs(10)]);
     /// ```
-    fn index_step<T>(self, start: T, step: T) -> Indexer<Self, T> where Self: Sized {
-        Indexer::new(self, start, step)
+    fn index_step_with<T, S>(self, start: T, step: S) -> MixedStepIndexer<Self, T, S> where Self: Sized {
+        MixedStepIndexer::new(self, start, step)
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself,
+    /// the step being the constant `STEP`.
+    ///
+    /// The iterator yields pairs `(i, val)`, where `i` is of type `T` and starts at 0 and increments
+    /// by `STEP`. Since the step is known at compile time, the multiplication of `nth` and of the
+    /// `DoubleEndedIterator` methods is made by a constant, which the compiler can optimize in tight loops.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so you may have to prevent it, depending on the type `T`
+    /// and the number of items generated by the source iterator.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `STEP` can't be converted to `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let words = vec!["alpha", "beta", "gamma", "delta"];
+    /// let mut offsets = words.into_iter().index_const_step::<u32, 8>();
+    ///
+    /// assert_eq!(offsets.next(), Some((0, "alpha")));
+    /// assert_eq!(offsets.nth(1), Some((16, "gamma")));
+    /// assert_eq!(offsets.next_back(), Some((24, "delta")));
+    /// ```
+    fn index_const_step<T, const STEP: usize>(self) -> ConstStepIndexer<Self, T, STEP>
+    where
+        Self: Sized,
+        u8: Into<T>,
+        T: TryFrom<usize>,
+    {
+        ConstStepIndexer::new(self, 0.into())
+    }
+
+    /// Creates a `rayon` parallel iterator which gives an index of the source iterator value as well as
+    /// the value itself, like `index::<T>().par_bridge()`.
+    ///
+    /// The values are processed in any order by the thread pool, but the index of each value is the one
+    /// it had in the source iterator, so the results can be put back in the original order with
+    /// [`ParallelReorder::reorder_by_index`].
+    ///
+    /// This method requires the `rayon` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let total = (1..=100).index_par_bridge::<u64>().map(|(i, x)| i * x).sum::<u64>();
+    ///
+    /// assert_eq!(total, (1..=100).map(|x| (x - 1) * x).sum());
+    /// ```
+    #[cfg(feature = "rayon")]
+    fn index_par_bridge<T>(self) -> rayon::iter::IterBridge<Indexer<Self, T>>
+    where
+        Self: Sized + Send,
+        Self::Item: Send,
+        u8: Into<T>,
+        T: IndexValue + Send,
+    {
+        rayon::iter::ParallelBridge::par_bridge(Indexer::new(self, 0.into(), 1.into()))
+    }
+
+    /// Creates an iterator which gives an index of the source iterator value as well as the value itself,
+    /// resuming the count from a [`CounterState`] snapshot.
+    ///
+    /// The snapshot is typically taken with [`Indexer::counter_state`], or by serializing the `Indexer`,
+    /// before stopping a job, and the source iterator is expected to be positioned at the next item.
+    /// The start value is restored, too, so [`Indexer::reset`] goes back to the start of the original job.
+    ///
+    /// This method requires the `serde` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::{CounterState, IndexerIterator};
+    ///
+    /// let mut job = (0..10).index_step::<u32>(100, 10);
+    /// assert_eq!(job.nth(3), Some((130, 3)));
+    /// let saved = serde_json::to_string(&job).unwrap();
+    /// assert_eq!(saved, r#"{"start":100,"counter":140,"step":10}"#);
+    ///
+    /// // later, with the remaining items
+    /// let state: CounterState<u32> = serde_json::from_str(&saved).unwrap();
+    /// let mut job = (4..10).index_resume(state);
+    /// assert_eq!(job.next(), Some((140, 4)));
+    /// ```
+    #[cfg(feature = "serde")]
+    fn index_resume<T>(self, state: CounterState<T>) -> Indexer<Self, T>
+    where
+        Self: Sized,
+    {
+        Indexer { iter: self, counter: state.counter, step: state.step, start: Some(state.start) }
+    }
+}
+
+impl<I, T: Clone> Indexer<I, T> {
+    /// Records the start value before the counter moves away from it for the first time, so that
+    /// [`reset`](Indexer::reset) can go back to it.
+    #[inline]
+    fn keep_start(&mut self) {
+        if self.start.is_none() {
+            self.start = Some(self.counter.clone());
+        }
     }
 }
 
@@ -147,18 +2941,17 @@ pub trait IndexerIterator {
 impl<I, T> Iterator for Indexer<I, T>
 where
     I: Iterator,
-    T: Clone + for<'a> AddAssign<&'a T> + From<u8> + TryFrom<usize>,
-    for<'a> &'a T: Add<Output=T> + Mul<Output=T>,
-    <T as TryFrom<usize>>::Error: Debug,
+    T: IndexValue,
 {
     type Item = (T, I::Item);
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.iter.next() {
             Some(v) => {
-                let result = Some((self.counter.clone(), v));
-                self.counter += &self.step;
-                result
+                self.keep_start();
+                // the next index is a new value rather than a clone, which matters for big integers
+                let next = self.counter.add_ref(&self.step);
+                Some((core::mem::replace(&mut self.counter, next), v))
             }
             None => None
         }
@@ -177,10 +2970,58 @@ where
     #[inline]
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         let a = self.iter.nth(n)?;
-        let nn: T = n.try_into().unwrap_or_else(|_| panic!("Cannot convert n into {}", std::any::type_name::<T>()));
-        let i = &self.counter + &(&nn * &self.step);
-        self.counter = &i + &self.step;
-        Some((i.clone(), a))
+        let i = add_steps(&self.counter, &self.step, n);
+        self.move_counter(i.add_ref(&self.step));
+        Some((i, a))
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        // when the number of items is known, the last index is computed directly
+        if let Some(len @ 1..) = self.iter.trusted_len() {
+            let i = add_steps(&self.counter, &self.step, len - 1);
+            return self.iter.last().map(|v| (i, v));
+        }
+        self.fold(None, |_, item| Some(item))
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut counter = self.counter;
+        let step = self.step;
+        self.iter.fold(init, |acc, v| {
+            let next = counter.add_ref(&step);
+            f(acc, (core::mem::replace(&mut counter, next), v))
+        })
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), core::num::NonZeroUsize> {
+        let result = self.iter.advance_by(n);
+        let advanced = n - result.err().map_or(0, |rem| rem.get());
+        self.move_counter(add_steps(&self.counter, &self.step, advanced));
+        result
+    }
+
+    // `Try` is unstable, so `try_fold` can only be overridden with a nightly compiler
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, Self::Item) -> R,
+        R: core::ops::Try<Output = B>,
+    {
+        self.keep_start();
+        let counter = &mut self.counter;
+        let step = &self.step;
+        self.iter.try_fold(init, |acc, v| {
+            let next = counter.add_ref(step);
+            f(acc, (core::mem::replace(counter, next), v))
+        })
     }
 }
 
@@ -190,26 +3031,44 @@ where
 impl<I, T> DoubleEndedIterator for Indexer<I, T>
 where
     I: ExactSizeIterator + DoubleEndedIterator,
-    T: Clone + Add<Output = T> + for<'a> AddAssign<&'a T> + From<u8> + TryFrom<usize>,
-    for<'a> &'a T: Add<Output=T> + Mul<Output=T>,
-    <T as TryFrom<usize>>::Error: Debug,
+    T: IndexValue,
 {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         let item = self.iter.next_back()?;
-        let len = self.iter.len();
-        let len: T = len.try_into().unwrap_or_else(|_| panic!("Cannot convert len = {len} into {}", std::any::type_name::<T>()));
         // counter + len * step must not overflow for T
-        Some((self.counter.clone() + &len * &self.step, item))
+        Some((add_steps(&self.counter, &self.step, self.iter.len()), item))
     }
 
     #[inline]
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
         let a = self.iter.nth_back(n)?;
-        let len = self.iter.len();
-        let len: T = len.try_into().unwrap_or_else(|_| panic!("Cannot convert len = {len} into {}", std::any::type_name::<T>()));
         // counter + len * step must not overflow for T
-        Some((self.counter.clone() + &len * &self.step, a))
+        Some((add_steps(&self.counter, &self.step, self.iter.len()), a))
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn advance_back_by(&mut self, n: usize) -> Result<(), core::num::NonZeroUsize> {
+        // the indices are computed from the front, so they're not affected
+        self.iter.advance_back_by(n)
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn try_rfold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, Self::Item) -> R,
+        R: core::ops::Try<Output = B>,
+    {
+        let mut len = self.iter.len();
+        let counter = &self.counter;
+        let step = &self.step;
+        self.iter.try_rfold(init, |acc, v| {
+            len -= 1;
+            // counter + len * step must not overflow for T
+            f(acc, (add_steps(counter, step, len), v))
+        })
     }
 }
 
@@ -218,15 +3077,117 @@ where
 impl<I, T> ExactSizeIterator for Indexer<I, T>
 where
     I: ExactSizeIterator,
-    T: Clone + for<'a> AddAssign<&'a T> + From<u8> + TryFrom<usize>,
-    for<'a> &'a T: Add<Output=T> + Mul<Output=T>,
-    <T as TryFrom<usize>>::Error: Debug,
+    T: IndexValue,
 {
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
+impl<I, T> FusedIterator for Indexer<I, T>
+where
+    I: FusedIterator,
+    T: IndexValue,
+{}
+
+//------------------------------------------------------------------------------
+// Display
+
+/// Summarizes the state of the iterator: the next index, the step and, when it's known from the size
+/// hint of the source iterator, the number of remaining items.
+///
+/// ```
+/// use iter_index::IndexerIterator;
+///
+/// let mut items = vec!["a", "b", "c"].into_iter().index_step::<u32>(100, 10);
+/// items.next();
+/// assert_eq!(items.to_string(), "next index 110, step 10, 2 remaining");
+///
+/// let items = (1..).index::<u8>();
+/// assert_eq!(items.to_string(), "next index 0, step 1");
+/// ```
+impl<I: Iterator, T: Display> Display for Indexer<I, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "next index {}, step {}", self.counter, self.step)?;
+        match self.iter.size_hint() {
+            (lo, Some(hi)) if lo == hi => write!(f, ", {lo} remaining"),
+            _ => Ok(()),
+        }
+    }
+}
+
+//------------------------------------------------------------------------------
+// In-place collection (nightly)
+
+#[cfg(feature = "nightly")]
+unsafe impl<I, T> core::iter::SourceIter for Indexer<I, T>
+where
+    I: core::iter::SourceIter,
+{
+    type Source = I::Source;
+
+    #[inline]
+    unsafe fn as_inner(&mut self) -> &mut I::Source {
+        // SAFETY: unsafe function forwarding to unsafe function with the same requirements
+        unsafe { core::iter::SourceIter::as_inner(&mut self.iter) }
+    }
+}
+
+#[cfg(feature = "nightly")]
+unsafe impl<I: core::iter::InPlaceIterable, T> core::iter::InPlaceIterable for Indexer<I, T> {
+    const EXPAND_BY: Option<core::num::NonZeroUsize> = I::EXPAND_BY;
+    const MERGE_BY: Option<core::num::NonZeroUsize> = I::MERGE_BY;
+}
+
+//------------------------------------------------------------------------------
+// Trusted length
+
+/// Gives the number of remaining items of an iterator when it can be relied upon, to calculate an
+/// index without counting the items.
+///
+/// Without the `nightly` feature, an exact size hint is trusted, as `ExactSizeIterator` requires it to
+/// be; a source iterator whose size hint is exact but wrong gets wrong indices. With the `nightly`
+/// feature, only the iterators which implement `TrustedLen` are trusted.
+trait TrustedCount {
+    fn trusted_len(&self) -> Option<usize>;
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<I: Iterator> TrustedCount for I {
+    #[inline]
+    fn trusted_len(&self) -> Option<usize> {
+        match self.size_hint() {
+            (lo, Some(hi)) if lo == hi => Some(lo),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<I: Iterator> TrustedCount for I {
+    #[inline]
+    default fn trusted_len(&self) -> Option<usize> {
+        None
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<I: core::iter::TrustedLen> TrustedCount for I {
+    #[inline]
+    fn trusted_len(&self) -> Option<usize> {
+        self.size_hint().1
+    }
+}
+
+
+// SAFETY: the size hint is the one of the source iterator, which is trusted
+#[cfg(feature = "nightly")]
+unsafe impl<I, T> core::iter::TrustedLen for Indexer<I, T>
+where
+    I: core::iter::TrustedLen,
+    T: IndexValue,
+{}
+
 //------------------------------------------------------------------------------
 // Blanket implementation
 
@@ -0,0 +1,85 @@
+// Copyright 2025 Redglyph
+//
+
+//------------------------------------------------------------------------------
+
+/// Implements the traits required by the index methods for a newtype wrapping an integer type.
+///
+/// `impl_index_type!(RowId as u32)` implements `From<u8>`, `TryFrom<usize>`, `AddAssign<&RowId>`,
+/// `Add` and `Mul` for `&RowId`, and [`CheckedIndex`](crate::CheckedIndex), for a tuple struct `RowId(u32)`,
+/// by forwarding to the inner type.
+/// The newtype must also implement `Clone`, which can be derived. Several types can be given at once,
+/// separated by commas.
+///
+/// # Examples
+///
+/// ```
+/// use iter_index::{impl_index_type, IndexerIterator};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct RowId(u32);
+///
+/// impl_index_type!(RowId as u32);
+///
+/// let rows = vec!["a", "b", "c"];
+/// let result = rows.into_iter().index_start(RowId(10)).collect::<Vec<_>>();
+///
+/// assert_eq!(result, vec![(RowId(10), "a"), (RowId(11), "b"), (RowId(12), "c")]);
+/// ```
+#[macro_export]
+macro_rules! impl_index_type {
+    ($($t:ident as $inner:ty),+ $(,)?) => {$(
+        impl ::core::convert::From<u8> for $t {
+            #[inline]
+            fn from(value: u8) -> Self {
+                Self(<$inner as ::core::convert::From<u8>>::from(value))
+            }
+        }
+
+        impl ::core::convert::TryFrom<usize> for $t {
+            type Error = <$inner as ::core::convert::TryFrom<usize>>::Error;
+
+            #[inline]
+            fn try_from(value: usize) -> ::core::result::Result<Self, Self::Error> {
+                <$inner as ::core::convert::TryFrom<usize>>::try_from(value).map(Self)
+            }
+        }
+
+        impl ::core::ops::AddAssign<&$t> for $t {
+            #[inline]
+            fn add_assign(&mut self, rhs: &$t) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl ::core::ops::Add for &$t {
+            type Output = $t;
+
+            #[inline]
+            fn add(self, rhs: &$t) -> $t {
+                $t(self.0 + rhs.0)
+            }
+        }
+
+        impl ::core::ops::Mul for &$t {
+            type Output = $t;
+
+            #[inline]
+            fn mul(self, rhs: &$t) -> $t {
+                $t(self.0 * rhs.0)
+            }
+        }
+
+        impl $crate::CheckedIndex for $t {
+            #[inline]
+            fn checked_add(&self, rhs: &Self) -> ::core::option::Option<Self> {
+                $crate::CheckedIndex::checked_add(&self.0, &rhs.0).map($t)
+            }
+
+            #[inline]
+            fn checked_mul(&self, rhs: &Self) -> ::core::option::Option<Self> {
+                $crate::CheckedIndex::checked_mul(&self.0, &rhs.0).map($t)
+            }
+        }
+    )+};
+}
@@ -0,0 +1,132 @@
+// Copyright 2025 Redglyph
+//
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// Creates an iterator which merges several indexed sources into one sequence ordered by index,
+/// yielding the pairs `(i, val)` of all the sources.
+///
+/// Each source must yield pairs `(i, val)` sorted by index, like the results of several shards of
+/// an indexed iterator. When several sources have the same index, the pair of the first source is
+/// yielded first. The merge uses a binary heap, so it works efficiently with many sources.
+///
+/// # Examples
+///
+/// ```
+/// use iter_index::merge_indexed;
+///
+/// let even = vec![(0, "a"), (2, "c"), (4, "e")];
+/// let odd = vec![(1, "b"), (3, "d")];
+/// let result = merge_indexed([even, odd]).collect::<Vec<_>>();
+///
+/// assert_eq!(result, vec![(0, "a"), (1, "b"), (2, "c"), (3, "d"), (4, "e")]);
+/// ```
+pub fn merge_indexed<S, T, V>(sources: S) -> MergeIndexed<<S::Item as IntoIterator>::IntoIter, T, V>
+where
+    S: IntoIterator,
+    S::Item: IntoIterator<Item = (T, V)>,
+    T: Ord,
+{
+    MergeIndexed::new(sources.into_iter().map(|s| s.into_iter()).collect())
+}
+
+/// An entry of the heap: the next pair of a source.
+#[derive(Clone, Debug)]
+struct Head<T, V> {
+    index: T,
+    source: usize,
+    value: V,
+}
+
+impl<T: Ord, V> PartialEq for Head<T, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Ord, V> Eq for Head<T, V> {}
+
+impl<T: Ord, V> PartialOrd for Head<T, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord, V> Ord for Head<T, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reversed, since BinaryHeap is a max-heap
+        (&other.index, other.source).cmp(&(&self.index, self.source))
+    }
+}
+
+/// An iterator that merges several indexed sources into one sequence ordered by index.
+///
+/// This `struct` is created by the [`merge_indexed`] function.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MergeIndexed<J, T, V> {
+    sources: Vec<J>,
+    heap: BinaryHeap<Head<T, V>>,
+    started: bool,
+}
+
+impl<J, T: Ord, V> MergeIndexed<J, T, V> {
+    pub fn new(sources: Vec<J>) -> MergeIndexed<J, T, V> {
+        MergeIndexed { heap: BinaryHeap::with_capacity(sources.len()), sources, started: false }
+    }
+}
+
+impl<J, T, V> MergeIndexed<J, T, V>
+where
+    J: Iterator<Item = (T, V)>,
+    T: Ord,
+{
+    fn pull(&mut self, source: usize) {
+        if let Some((index, value)) = self.sources[source].next() {
+            self.heap.push(Head { index, source, value });
+        }
+    }
+}
+
+impl<J, T, V> Iterator for MergeIndexed<J, T, V>
+where
+    J: Iterator<Item = (T, V)>,
+    T: Ord,
+{
+    type Item = (T, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            for source in 0..self.sources.len() {
+                self.pull(source);
+            }
+        }
+        let Head { index, source, value } = self.heap.pop()?;
+        self.pull(source);
+        Some((index, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.heap.len();
+        self.sources.iter().map(|s| s.size_hint()).fold((n, Some(n)), |(lo, hi), (s_lo, s_hi)| {
+            (lo.saturating_add(s_lo), hi.and_then(|hi| s_hi.and_then(|s_hi| hi.checked_add(s_hi))))
+        })
+    }
+}
+
+impl<J, T, V> ExactSizeIterator for MergeIndexed<J, T, V>
+where
+    J: ExactSizeIterator<Item = (T, V)>,
+    T: Ord,
+{}
+
+impl<J, T, V> FusedIterator for MergeIndexed<J, T, V>
+where
+    J: FusedIterator<Item = (T, V)>,
+    T: Ord,
+{}
@@ -0,0 +1,86 @@
+// Copyright 2025 Redglyph
+//
+
+use metrics::{Counter, Gauge, KeyName};
+
+//------------------------------------------------------------------------------
+
+/// An iterator that passes the items of the source iterator through, while recording them with
+/// the [`metrics`] crate.
+///
+/// This `struct` is created by the [`record_metrics`](crate::IndexerIterator::record_metrics)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MetricsIter<I, F> {
+    iter: I,
+    counter: Counter,
+    gauge: Option<(Gauge, F)>,
+}
+
+impl<I: Iterator> MetricsIter<I, fn(&I::Item) -> f64> {
+    pub fn new(iter: I, counter: Counter) -> Self {
+        MetricsIter { iter, counter, gauge: None }
+    }
+}
+
+impl<I, F> MetricsIter<I, F> {
+    /// Sets a [`metrics::gauge!`] to the value returned by `value` for each item, typically the
+    /// typed index of an indexed iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let items = vec!["a", "b", "c"];
+    /// let result = items.into_iter()
+    ///     .index::<u32>()
+    ///     .record_metrics("items_processed")
+    ///     .with_gauge("current_index", |(i, _)| *i as f64)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(result, vec![(0, "a"), (1, "b"), (2, "c")]);
+    /// ```
+    pub fn with_gauge<N, G>(self, name: N, value: G) -> MetricsIter<I, G>
+    where
+        I: Iterator,
+        N: Into<KeyName>,
+        G: FnMut(&I::Item) -> f64,
+    {
+        let name = name.into();
+        MetricsIter { iter: self.iter, counter: self.counter, gauge: Some((metrics::gauge!(name), value)) }
+    }
+}
+
+impl<I, F> Iterator for MetricsIter<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> f64,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.counter.increment(1);
+        if let Some((gauge, value)) = &mut self.gauge {
+            gauge.set(value(&item));
+        }
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> ExactSizeIterator for MetricsIter<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(&I::Item) -> f64,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
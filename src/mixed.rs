@@ -0,0 +1,95 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use core::ops::Add;
+use crate::value::add_steps_with;
+
+//------------------------------------------------------------------------------
+
+/// Returns `counter + n * step`, with the steps added by powers of two, like the index of
+/// [`Indexer`](crate::Indexer) but by value.
+fn add_steps<T, S>(counter: &T, step: &S, n: usize) -> T
+where
+    T: Clone + Add<S, Output = T>,
+    S: Clone + Add<Output = S>,
+{
+    add_steps_with(counter.clone(), step.clone(), n, |i, s| i + s.clone(), |s| s.clone() + s.clone())
+}
+
+/// An iterator that yields an index whose step is of another type, like an `Instant` with a `Duration`
+/// step, and the iteration item.
+///
+/// This `struct` is created by the [`index_step_with`](crate::IndexerIterator::index_step_with)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MixedStepIndexer<I, T, S> {
+    iter: I,
+    counter: T,
+    step: S,
+}
+
+impl<I, T, S> MixedStepIndexer<I, T, S> {
+    pub fn new(iter: I, start: T, step: S) -> MixedStepIndexer<I, T, S> {
+        MixedStepIndexer { iter, counter: start, step }
+    }
+}
+
+impl<I, T, S> Iterator for MixedStepIndexer<I, T, S>
+where
+    I: Iterator,
+    T: Clone + Add<S, Output = T>,
+    S: Clone + Add<Output = S>,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let next = self.counter.clone() + self.step.clone();
+        Some((core::mem::replace(&mut self.counter, next), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let item = self.iter.nth(n)?;
+        let i = add_steps(&self.counter, &self.step, n);
+        self.counter = i.clone() + self.step.clone();
+        Some((i, item))
+    }
+}
+
+impl<I, T, S> DoubleEndedIterator for MixedStepIndexer<I, T, S>
+where
+    I: ExactSizeIterator + DoubleEndedIterator,
+    T: Clone + Add<S, Output = T>,
+    S: Clone + Add<Output = S>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        Some((add_steps(&self.counter, &self.step, self.iter.len()), item))
+    }
+}
+
+impl<I, T, S> ExactSizeIterator for MixedStepIndexer<I, T, S>
+where
+    I: ExactSizeIterator,
+    T: Clone + Add<S, Output = T>,
+    S: Clone + Add<Output = S>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T, S> FusedIterator for MixedStepIndexer<I, T, S>
+where
+    I: FusedIterator,
+    T: Clone + Add<S, Output = T>,
+    S: Clone + Add<Output = S>,
+{}
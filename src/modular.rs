@@ -0,0 +1,70 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields an index wrapping around at a modulus, and the iteration item.
+///
+/// This `struct` is created by the [`index_mod`](crate::IndexerIterator::index_mod)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ModIndexer<I, T> {
+    iter: I,
+    counter: T,
+    modulus: T,
+    first: bool,
+}
+
+impl<I, T: PartialEq + From<u8>> ModIndexer<I, T> {
+    pub fn new(iter: I, modulus: T) -> ModIndexer<I, T> {
+        assert!(modulus != T::from(0), "the modulus must be greater than 0");
+        ModIndexer { iter, counter: T::from(0), modulus, first: true }
+    }
+}
+
+impl<I, T> Iterator for ModIndexer<I, T>
+where
+    I: Iterator,
+    T: IndexValue + PartialEq + From<u8>,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        // the counter is moved before yielding the index, so it never goes beyond the modulus
+        if self.first {
+            self.first = false;
+        } else {
+            self.counter.add_assign_ref(&T::from(1));
+            if self.counter == self.modulus {
+                self.counter = T::from(0);
+            }
+        }
+        Some((self.counter.clone(), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for ModIndexer<I, T>
+where
+    I: ExactSizeIterator,
+    T: IndexValue + PartialEq + From<u8>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for ModIndexer<I, T>
+where
+    I: FusedIterator,
+    T: IndexValue + PartialEq + From<u8>,
+{}
@@ -0,0 +1,66 @@
+// Copyright 2025 Redglyph
+//
+
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that passes the indexed items of the source iterator through, and which allows
+/// to peek at a bounded number of upcoming items, along with their index.
+///
+/// This `struct` is created by the [`multipeek_indexed`](crate::IndexerIterator::multipeek_indexed)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MultiPeek<I: Iterator> {
+    iter: I,
+    buffer: VecDeque<I::Item>,
+    lookahead: usize,
+}
+
+impl<I: Iterator> MultiPeek<I> {
+    pub fn new(iter: I, lookahead: usize) -> MultiPeek<I> {
+        MultiPeek { iter, buffer: VecDeque::with_capacity(lookahead), lookahead }
+    }
+
+    /// Returns a reference to the `j`-th upcoming item, without advancing the iterator, or `None`
+    /// if the source iterator ends before that item. `peek_nth(0)` is the item returned by the next
+    /// call to `next()`.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `j` isn't smaller than the lookahead given when the adapter was created.
+    pub fn peek_nth(&mut self, j: usize) -> Option<&I::Item> {
+        assert!(j < self.lookahead, "cannot peek beyond the lookahead of {} items", self.lookahead);
+        while self.buffer.len() <= j {
+            self.buffer.push_back(self.iter.next()?);
+        }
+        self.buffer.get(j)
+    }
+
+    /// Returns a reference to the next item, without advancing the iterator; it's equivalent to
+    /// `peek_nth(0)`.
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.peek_nth(0)
+    }
+}
+
+impl<I: Iterator> Iterator for MultiPeek<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buffer.pop_front().or_else(|| self.iter.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        let n = self.buffer.len();
+        (lo.saturating_add(n), hi.and_then(|hi| hi.checked_add(n)))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for MultiPeek<I> {}
+
+impl<I: FusedIterator> FusedIterator for MultiPeek<I> {}
@@ -0,0 +1,112 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+//------------------------------------------------------------------------------
+
+/// The unsigned non-zero integer types, which can be used as indices by [`NonZeroIndexer`].
+///
+/// The signed types aren't supported, since adding a step could give 0.
+pub trait NonZeroIndex: Copy {
+    /// The value 1.
+    const ONE: Self;
+
+    /// Returns `self + n * step`, or `None` if the result overflows.
+    fn checked_add_steps(self, step: Self, n: usize) -> Option<Self>;
+}
+
+macro_rules! impl_nonzero_index {
+    ($($t:ty),*) => {$(
+        impl NonZeroIndex for $t {
+            const ONE: Self = match <$t>::new(1) {
+                Some(one) => one,
+                None => unreachable!(),
+            };
+
+            #[inline]
+            fn checked_add_steps(self, step: Self, n: usize) -> Option<Self> {
+                let offset = step.get().checked_mul(n.try_into().ok()?)?;
+                // the sum of a non-zero value and a positive offset is never 0
+                <$t>::new(self.get().checked_add(offset)?)
+            }
+        }
+    )*};
+}
+
+impl_nonzero_index!(NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields a non-zero index and the iteration item.
+///
+/// This `struct` is created by the [`index_nonzero`](crate::IndexerIterator::index_nonzero) and
+/// [`index_nonzero_step`](crate::IndexerIterator::index_nonzero_step) methods on
+/// [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct NonZeroIndexer<I, T> {
+    iter: I,
+    // `None` once the next index has overflowed, which only panics if that index is used
+    counter: Option<T>,
+    step: T,
+}
+
+impl<I, T> NonZeroIndexer<I, T> {
+    pub fn new(iter: I, start: T, step: T) -> NonZeroIndexer<I, T> {
+        NonZeroIndexer { iter, counter: Some(start), step }
+    }
+}
+
+impl<I, T: NonZeroIndex> NonZeroIndexer<I, T> {
+    /// Returns the index `n` steps after the next one.
+    fn index(&self, n: usize) -> T {
+        self.counter
+            .and_then(|i| i.checked_add_steps(self.step, n))
+            .unwrap_or_else(|| panic!("the index overflowed {}", core::any::type_name::<T>()))
+    }
+}
+
+impl<I: Iterator, T: NonZeroIndex> Iterator for NonZeroIndexer<I, T> {
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let i = self.index(0);
+        self.counter = i.checked_add_steps(self.step, 1);
+        Some((i, item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let item = self.iter.nth(n)?;
+        let i = self.index(n);
+        self.counter = i.checked_add_steps(self.step, 1);
+        Some((i, item))
+    }
+}
+
+impl<I, T> DoubleEndedIterator for NonZeroIndexer<I, T>
+where
+    I: ExactSizeIterator + DoubleEndedIterator,
+    T: NonZeroIndex,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        Some((self.index(self.iter.len()), item))
+    }
+}
+
+impl<I: ExactSizeIterator, T: NonZeroIndex> ExactSizeIterator for NonZeroIndexer<I, T> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedIterator, T: NonZeroIndex> FusedIterator for NonZeroIndexer<I, T> {}
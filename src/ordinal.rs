@@ -0,0 +1,80 @@
+// Copyright 2025 Redglyph
+//
+
+use core::fmt::{Display, Formatter};
+use core::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// An English ordinal label: `1st`, `2nd`, `3rd`, `4th`, ...
+///
+/// The label is only rendered when it's displayed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ordinal(usize);
+
+impl Ordinal {
+    /// Returns the number of the ordinal.
+    pub fn value(&self) -> usize {
+        self.0
+    }
+
+    /// Returns the suffix of the ordinal: `st`, `nd`, `rd` or `th`.
+    pub fn suffix(&self) -> &'static str {
+        match (self.0 % 10, self.0 % 100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        }
+    }
+}
+
+impl Display for Ordinal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}{}", self.0, self.suffix())
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields an [`Ordinal`] label and the iteration item.
+///
+/// This `struct` is created by the [`index_ordinal`](crate::IndexerIterator::index_ordinal)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OrdinalIndexer<I> {
+    iter: I,
+    next: usize,
+}
+
+impl<I> OrdinalIndexer<I> {
+    pub fn new(iter: I, start: usize) -> OrdinalIndexer<I> {
+        OrdinalIndexer { iter, next: start }
+    }
+}
+
+impl<I: Iterator> Iterator for OrdinalIndexer<I> {
+    type Item = (Ordinal, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let v = self.iter.next()?;
+        let result = Some((Ordinal(self.next), v));
+        self.next += 1;
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for OrdinalIndexer<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for OrdinalIndexer<I> {}
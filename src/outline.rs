@@ -0,0 +1,104 @@
+// Copyright 2025 Redglyph
+//
+
+use std::fmt::{Display, Formatter};
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// A hierarchical outline label, like `2.1.3`, made of the index at each level.
+///
+/// The label is only rendered when it's displayed, so the conversion to a string costs nothing if
+/// only the path is used.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Outline<T>(Vec<T>);
+
+impl<T> Outline<T> {
+    /// Returns the index at each level, from the top level down to the item's level.
+    pub fn path(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Returns the depth of the label, the top level being 0.
+    pub fn depth(&self) -> usize {
+        self.0.len() - 1
+    }
+
+    /// Consumes the label and returns the index at each level.
+    pub fn into_path(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T: Display> Display for Outline<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (n, i) in self.0.iter().enumerate() {
+            if n > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{i}")?;
+        }
+        Ok(())
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields a hierarchical [`Outline`] label and the iteration item, the level of
+/// each item being given by a closure.
+///
+/// This `struct` is created by the [`index_outline`](crate::IndexerIterator::index_outline)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OutlineIndexer<I, T, F> {
+    iter: I,
+    path: Vec<T>,
+    start: T,
+    step: T,
+    depth: F,
+}
+
+impl<I, T, F> OutlineIndexer<I, T, F> {
+    pub fn new(iter: I, start: T, step: T, depth: F) -> OutlineIndexer<I, T, F> {
+        OutlineIndexer { iter, path: Vec::new(), start, step, depth }
+    }
+}
+
+impl<I, T, F> Iterator for OutlineIndexer<I, T, F>
+where
+    I: Iterator,
+    T: IndexValue,
+    F: FnMut(&I::Item) -> usize,
+{
+    type Item = (Outline<T>, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let depth = (self.depth)(&item);
+        if depth < self.path.len() {
+            self.path.truncate(depth + 1);
+            self.path[depth].add_assign_ref(&self.step);
+        } else {
+            // skipped levels, if any, get the start value too
+            self.path.resize(depth + 1, self.start.clone());
+        }
+        Some((Outline(self.path.clone()), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, F> ExactSizeIterator for OutlineIndexer<I, T, F>
+where
+    I: ExactSizeIterator,
+    T: IndexValue,
+    F: FnMut(&I::Item) -> usize,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use crate::checked::checked_add_steps;
+use crate::{CheckedIndex, SaturatingIndex};
+
+//------------------------------------------------------------------------------
+
+/// The behaviour of [`OverflowIndexer`] when the index overflows its type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Panics when an item would get an index that overflowed, even in release mode.
+    Panic,
+    /// Clamps the index at the numeric bounds of the type.
+    Saturate,
+    /// Wraps the index around the numeric bounds of the type.
+    Wrap,
+    /// Ends the iteration when an item would get an index that overflowed.
+    StopIteration,
+}
+
+/// The arithmetic operations needed by all the [`OverflowPolicy`] variants.
+///
+/// The trait is implemented for the primitive integer types.
+pub trait OverflowIndex: CheckedIndex + SaturatingIndex + Clone {
+    /// Returns `self + rhs`, wrapped around the numeric bounds of the type.
+    fn wrapping_add(&self, rhs: &Self) -> Self;
+}
+
+macro_rules! impl_overflow_index {
+    ($($t:ty),*) => {$(
+        impl OverflowIndex for $t {
+            #[inline]
+            fn wrapping_add(&self, rhs: &Self) -> Self {
+                <$t>::wrapping_add(*self, *rhs)
+            }
+        }
+    )*};
+}
+
+impl_overflow_index!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Returns the number of items, at most `n`, which get an index from `counter` before it overflows.
+fn valid_count<T: OverflowIndex>(counter: &T, step: &T, n: usize) -> usize {
+    // the indices only increase or only decrease, so the first `k` indices are valid if the last one is
+    let (mut lo, mut hi) = (0, n);
+    while lo < hi {
+        let mid = hi - (hi - lo) / 2;
+        if checked_add_steps(counter, step, mid - 1).is_some() {
+            lo = mid;
+        } else {
+            hi = mid - 1;
+        }
+    }
+    lo
+}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the current count and the iteration item, handling the overflow of the
+/// count according to an [`OverflowPolicy`].
+///
+/// This `struct` is created by the [`with_overflow_policy`](crate::Indexer::with_overflow_policy)
+/// method on [`Indexer`](crate::Indexer).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OverflowIndexer<I, T> {
+    iter: I,
+    counter: Option<T>,
+    step: T,
+    policy: OverflowPolicy,
+}
+
+impl<I, T> OverflowIndexer<I, T> {
+    pub fn new(iter: I, start: T, step: T, policy: OverflowPolicy) -> OverflowIndexer<I, T> {
+        OverflowIndexer { iter, counter: Some(start), step, policy }
+    }
+
+    /// Returns the overflow policy.
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+}
+
+impl<I, T> Iterator for OverflowIndexer<I, T>
+where
+    I: Iterator,
+    T: OverflowIndex,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.counter.is_none() && self.policy == OverflowPolicy::StopIteration {
+            return None;
+        }
+        let item = self.iter.next()?;
+        // the counter can only be `None` with the `Panic` policy at this point
+        let i = self.counter.take().unwrap_or_else(|| panic!("the index overflowed {}", core::any::type_name::<T>()));
+        self.counter = match self.policy {
+            OverflowPolicy::Panic | OverflowPolicy::StopIteration => i.checked_add(&self.step),
+            OverflowPolicy::Saturate => Some(i.saturating_add(&self.step)),
+            OverflowPolicy::Wrap => Some(i.wrapping_add(&self.step)),
+        };
+        Some((i, item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match (self.policy, &self.counter) {
+            (OverflowPolicy::StopIteration, None) => (0, Some(0)),
+            // the iteration stops before the end of the source iterator if the index overflows
+            (OverflowPolicy::StopIteration, Some(counter)) => {
+                let (lo, hi) = self.iter.size_hint();
+                let hi = match hi {
+                    Some(hi) => Some(valid_count(counter, &self.step, hi)),
+                    // an unbounded source is bounded by the index
+                    None => Some(valid_count(counter, &self.step, usize::MAX)).filter(|&n| n < usize::MAX),
+                };
+                (valid_count(counter, &self.step, lo), hi)
+            }
+            _ => self.iter.size_hint(),
+        }
+    }
+}
+
+impl<I, T> ExactSizeIterator for OverflowIndexer<I, T>
+where
+    I: ExactSizeIterator,
+    T: OverflowIndex,
+{}
+
+impl<I, T> FusedIterator for OverflowIndexer<I, T>
+where
+    I: FusedIterator,
+    T: OverflowIndex,
+{}
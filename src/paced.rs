@@ -0,0 +1,65 @@
+// Copyright 2025 Redglyph
+//
+
+use std::iter::FusedIterator;
+use std::thread;
+use std::time::{Duration, Instant};
+
+//------------------------------------------------------------------------------
+
+/// Returns `n * step`, computed in nanoseconds and saturated to [`Duration::MAX`].
+fn offset(step: Duration, n: u64) -> Duration {
+    let nanos = step.as_nanos().saturating_mul(n as u128);
+    match u64::try_from(nanos / 1_000_000_000) {
+        Ok(secs) => Duration::new(secs, (nanos % 1_000_000_000) as u32),
+        Err(_) => Duration::MAX,
+    }
+}
+
+/// An iterator that passes the items of the source iterator through at a fixed rate.
+///
+/// This `struct` is created by the [`paced`](crate::IndexerIterator::paced)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Paced<I> {
+    iter: I,
+    step: Duration,
+    start: Option<Instant>,
+    count: u64,
+}
+
+impl<I> Paced<I> {
+    pub fn new(iter: I, step: Duration) -> Paced<I> {
+        Paced { iter, step, start: None, count: 0 }
+    }
+}
+
+impl<I: Iterator> Iterator for Paced<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = *self.start.get_or_insert_with(Instant::now);
+        let item = self.iter.next()?;
+        // item k mustn't be yielded before start + k * step (saturated if it overflows)
+        let wait = offset(self.step, self.count).saturating_sub(start.elapsed());
+        if !wait.is_zero() {
+            thread::sleep(wait);
+        }
+        self.count += 1;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Paced<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for Paced<I> {}
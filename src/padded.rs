@@ -0,0 +1,83 @@
+// Copyright 2025 Redglyph
+//
+
+use core::fmt::{Display, Formatter};
+use core::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// A decimal index label, zero-padded to a fixed width, like `007`.
+///
+/// The label is only rendered when it's displayed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Padded {
+    value: usize,
+    width: usize,
+}
+
+impl Padded {
+    /// Returns the value of the index.
+    pub fn value(&self) -> usize {
+        self.value
+    }
+
+    /// Returns the width of the label.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+}
+
+impl Display for Padded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:0width$}", self.value, width = self.width)
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields a [`Padded`] label and the iteration item.
+///
+/// This `struct` is created by the [`index_padded`](crate::IndexerIterator::index_padded)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PaddedIndexer<I> {
+    iter: I,
+    next: Padded,
+}
+
+impl<I: ExactSizeIterator> PaddedIndexer<I> {
+    pub fn new(iter: I, start: usize) -> PaddedIndexer<I> {
+        let mut last = start + iter.len().saturating_sub(1);
+        let mut width = 1;
+        while last >= 10 {
+            last /= 10;
+            width += 1;
+        }
+        PaddedIndexer { iter, next: Padded { value: start, width } }
+    }
+}
+
+impl<I: Iterator> Iterator for PaddedIndexer<I> {
+    type Item = (Padded, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let v = self.iter.next()?;
+        let result = Some((self.next, v));
+        self.next.value += 1;
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for PaddedIndexer<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for PaddedIndexer<I> {}
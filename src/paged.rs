@@ -0,0 +1,69 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use crate::IndexValue;
+use crate::value::to_index;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the page number and the position on the page of each item, and the
+/// item itself.
+///
+/// This `struct` is created by the [`index_paged`](crate::IndexerIterator::index_paged)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PagedIndexer<I, T> {
+    iter: I,
+    page_size: usize,
+    page: T,
+    position: usize,
+}
+
+impl<I, T> PagedIndexer<I, T> {
+    pub fn new(iter: I, page_size: usize, first_page: T) -> PagedIndexer<I, T> {
+        assert!(page_size > 0, "the page size must be greater than 0");
+        PagedIndexer { iter, page_size, page: first_page, position: 0 }
+    }
+}
+
+impl<I, T> Iterator for PagedIndexer<I, T>
+where
+    I: Iterator,
+    T: IndexValue + From<u8> + TryFrom<usize>,
+{
+    type Item = ((T, T), I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let result = Some(((self.page.clone(), to_index(self.position)), item));
+        self.position += 1;
+        if self.position == self.page_size {
+            self.position = 0;
+            self.page.add_assign_ref(&T::from(1));
+        }
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for PagedIndexer<I, T>
+where
+    I: ExactSizeIterator,
+    T: IndexValue + From<u8> + TryFrom<usize>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for PagedIndexer<I, T>
+where
+    I: FusedIterator,
+    T: IndexValue + From<u8> + TryFrom<usize>,
+{}
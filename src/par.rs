@@ -0,0 +1,209 @@
+// Copyright 2025 Redglyph
+//
+
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use rayon::slice::ParallelSliceMut;
+use crate::value::add_steps;
+use crate::{IndexValue, Indexer};
+
+//------------------------------------------------------------------------------
+
+/// An extension trait which provides the indexing methods of [`IndexerIterator`](crate::IndexerIterator)
+/// on the indexed parallel iterators of `rayon`.
+///
+/// Each item gets the index it would have in the sequential iteration: when the work is split between
+/// threads, the second part starts at the index of its first item.
+///
+/// This trait requires the `rayon` feature.
+///
+/// # Examples
+///
+/// ```
+/// use rayon::prelude::*;
+/// use iter_index::ParallelIndexerIterator;
+///
+/// let items = vec![10, 20, 30, 40];
+/// let result = items.par_iter().index_step::<u64>(1000, 8).map(|(i, x)| i + x).collect::<Vec<_>>();
+///
+/// assert_eq!(result, vec![1010, 1028, 1046, 1064]);
+/// ```
+pub trait ParallelIndexerIterator: IndexedParallelIterator {
+    /// Creates a parallel iterator which gives an index of each value as well as the value itself;
+    /// see [`IndexerIterator::index`](crate::IndexerIterator::index).
+    fn index<T>(self) -> ParIndexer<Self, T> where u8: Into<T> {
+        ParIndexer::new(self, 0.into(), 1.into())
+    }
+
+    /// Creates a parallel iterator which gives an index of each value as well as the value itself;
+    /// see [`IndexerIterator::index_start`](crate::IndexerIterator::index_start).
+    fn index_start<T>(self, start: T) -> ParIndexer<Self, T> where u8: Into<T> {
+        ParIndexer::new(self, start, 1.into())
+    }
+
+    /// Creates a parallel iterator which gives an index of each value as well as the value itself;
+    /// see [`IndexerIterator::index_step`](crate::IndexerIterator::index_step).
+    fn index_step<T>(self, start: T, step: T) -> ParIndexer<Self, T> {
+        ParIndexer::new(self, start, step)
+    }
+}
+
+impl<I: IndexedParallelIterator> ParallelIndexerIterator for I {}
+
+//------------------------------------------------------------------------------
+
+/// A parallel iterator that yields the current count, with the generic type, and the iteration item.
+///
+/// This `struct` is created by the methods of [`ParallelIndexerIterator`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ParIndexer<I, T> {
+    base: I,
+    start: T,
+    step: T,
+}
+
+impl<I, T> ParIndexer<I, T> {
+    pub fn new(base: I, start: T, step: T) -> ParIndexer<I, T> {
+        ParIndexer { base, start, step }
+    }
+}
+
+impl<I, T> ParallelIterator for ParIndexer<I, T>
+where
+    I: IndexedParallelIterator,
+    T: IndexValue + Send,
+{
+    type Item = (T, I::Item);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.base.len())
+    }
+}
+
+impl<I, T> IndexedParallelIterator for ParIndexer<I, T>
+where
+    I: IndexedParallelIterator,
+    T: IndexValue + Send,
+{
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.base.len()
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        return self.base.with_producer(Callback { callback, start: self.start, step: self.step });
+
+        struct Callback<CB, T> {
+            callback: CB,
+            start: T,
+            step: T,
+        }
+
+        impl<CB, T, V> ProducerCallback<V> for Callback<CB, T>
+        where
+            CB: ProducerCallback<(T, V)>,
+            T: IndexValue + Send,
+        {
+            type Output = CB::Output;
+
+            fn callback<P>(self, base: P) -> CB::Output
+            where
+                P: Producer<Item = V>,
+            {
+                self.callback.callback(IndexProducer { base, start: self.start, step: self.step })
+            }
+        }
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The producer of [`ParIndexer`], which gives its first index to each part when it's split.
+struct IndexProducer<P, T> {
+    base: P,
+    start: T,
+    step: T,
+}
+
+impl<P, T> Producer for IndexProducer<P, T>
+where
+    P: Producer,
+    T: IndexValue + Send,
+{
+    type Item = (T, P::Item);
+    type IntoIter = Indexer<P::IntoIter, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Indexer::new(self.base.into_iter(), self.start, self.step)
+    }
+
+    fn min_len(&self) -> usize {
+        self.base.min_len()
+    }
+
+    fn max_len(&self) -> usize {
+        self.base.max_len()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.base.split_at(index);
+        let right_start = add_steps(&self.start, &self.step, index);
+        (
+            IndexProducer { base: left, start: self.start, step: self.step.clone() },
+            IndexProducer { base: right, start: right_start, step: self.step },
+        )
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// An extension trait which puts back in order the indexed items of a `rayon` parallel iterator whose
+/// order has been lost, typically after [`index_par_bridge`](crate::IndexerIterator::index_par_bridge).
+///
+/// This trait requires the `rayon` feature.
+///
+/// # Examples
+///
+/// ```
+/// use rayon::prelude::*;
+/// use iter_index::{IndexerIterator, ParallelReorder};
+///
+/// let lines = vec!["a", "bb", "ccc", "dddd"];
+/// let result = lines.into_iter()
+///     .index_par_bridge::<u32>()
+///     .map(|(i, s)| (i, s.len()))
+///     .reorder_by_index();
+///
+/// assert_eq!(result, vec![(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// ```
+pub trait ParallelReorder: ParallelIterator {
+    /// Collects the indexed items `(i, val)` and returns them sorted by their index. Unlike
+    /// [`IndexerIterator::reorder_by_index`](crate::IndexerIterator::reorder_by_index), the indices
+    /// don't need to be consecutive, and the sort is made in parallel once all the items are collected.
+    fn reorder_by_index<T, V>(self) -> Vec<(T, V)>
+    where
+        Self: ParallelIterator<Item = (T, V)>,
+        T: Ord + Send,
+        V: Send,
+    {
+        let mut items = self.collect::<Vec<_>>();
+        items.par_sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        items
+    }
+}
+
+impl<I: ParallelIterator> ParallelReorder for I {}
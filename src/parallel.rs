@@ -0,0 +1,80 @@
+// Copyright 2025 Redglyph
+//
+
+use std::mem;
+use std::thread;
+use crate::{add_steps, IndexValue, Indexer};
+
+//------------------------------------------------------------------------------
+
+impl<I, T> Indexer<I, T>
+where
+    I: ExactSizeIterator + Clone + Send,
+    T: IndexValue + Sync,
+{
+    /// Calls `f(i, val)` on each indexed value of the source iterator, from `threads` threads running in
+    /// parallel.
+    ///
+    /// The remaining items of the source iterator are split into chunks of `chunk` items, which are
+    /// distributed to the threads in turn. Each thread works on its own clone of the source iterator,
+    /// which it advances to its chunks with `nth`, and calculates the index of the first item of each
+    /// chunk from its position, so the threads don't share any state and each value is processed with
+    /// its global index. The calling thread is one of the `threads` threads, and the method returns when
+    /// all the values have been processed.
+    ///
+    /// The source iterator is cloned once per thread, so it should be cheap to clone and to advance, like
+    /// a slice iterator or a range.
+    ///
+    /// This method requires the `std` feature, and doesn't depend on any thread pool crate.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `threads` or `chunk` is 0, or if `f` panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicU64, Ordering};
+    /// use iter_index::IndexerIterator;
+    ///
+    /// let values = (0..1000_u64).collect::<Vec<_>>();
+    /// let total = AtomicU64::new(0);
+    /// values.iter().index_start::<u64>(1).for_each_indexed_parallel(4, 64, |i, x| {
+    ///     total.fetch_add(i * x, Ordering::Relaxed);
+    /// });
+    ///
+    /// assert_eq!(total.into_inner(), (0..1000).map(|x| (x + 1) * x).sum());
+    /// ```
+    pub fn for_each_indexed_parallel<F>(self, threads: usize, chunk: usize, f: F)
+    where
+        F: Fn(T, I::Item) + Sync,
+    {
+        assert!(threads != 0, "threads must be greater than 0");
+        assert!(chunk != 0, "chunk must be greater than 0");
+        let len = self.iter.len();
+        let chunks = len / chunk + usize::from(len % chunk != 0);
+        let (counter, step, f) = (&self.counter, &self.step, &f);
+        let worker = move |first: usize, mut source: I| {
+            // number of items already taken from `source`
+            let mut position = 0;
+            for start in (first..chunks).step_by(threads).map(|c| c * chunk) {
+                if start > position {
+                    source.nth(start - position - 1);
+                }
+                let mut i = add_steps(counter, step, start);
+                for v in source.by_ref().take(chunk) {
+                    let next = i.add_ref(step);
+                    f(mem::replace(&mut i, next), v);
+                }
+                position = start + chunk;
+            }
+        };
+        thread::scope(|s| {
+            for t in 1..threads.min(chunks) {
+                let source = self.iter.clone();
+                s.spawn(move || worker(t, source));
+            }
+            worker(0, self.iter.clone());
+        });
+    }
+}
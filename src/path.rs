@@ -0,0 +1,144 @@
+// Copyright 2025 Redglyph
+//
+
+use std::fmt::{Debug, Formatter};
+use std::iter::FusedIterator;
+use smallvec::{smallvec, SmallVec};
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// The path of an item in a nested structure, made of the index at each nesting level.
+pub type IndexPath<T> = SmallVec<[T; 4]>;
+
+/// A node of a nested structure traversed by [`path_indexed`](crate::IndexerIterator::path_indexed),
+/// which is either a leaf or a container of other nodes.
+///
+/// # Examples
+///
+/// ```
+/// use iter_index::Nested;
+///
+/// enum Json { Number(f64), Array(Vec<Json>) }
+///
+/// impl Nested for Json {
+///     type Children = std::vec::IntoIter<Json>;
+///
+///     fn into_children(self) -> Result<Self::Children, Self> {
+///         match self {
+///             Json::Array(items) => Ok(items.into_iter()),
+///             leaf => Err(leaf),
+///         }
+///     }
+/// }
+/// ```
+pub trait Nested: Sized {
+    /// The iterator over the children of a container.
+    type Children: Iterator<Item = Self>;
+
+    /// Returns an iterator over the children of the node if it's a container, or gives the node back
+    /// if it's a leaf.
+    fn into_children(self) -> Result<Self::Children, Self>;
+}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that traverses a nested structure depth-first, and which yields the path of each leaf
+/// and the leaf itself.
+///
+/// This `struct` is created by the [`path_indexed`](crate::IndexerIterator::path_indexed)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PathIndexer<I, N: Nested, T> {
+    iter: I,
+    stack: Vec<N::Children>,
+    path: IndexPath<T>,
+    start: T,
+    step: T,
+}
+
+impl<I, N: Nested, T: Clone> PathIndexer<I, N, T> {
+    pub fn new(iter: I, start: T, step: T) -> PathIndexer<I, N, T> {
+        PathIndexer { iter, stack: Vec::new(), path: smallvec![start.clone()], start, step }
+    }
+}
+
+impl<I, N, T> Clone for PathIndexer<I, N, T>
+where
+    I: Clone,
+    N: Nested,
+    N::Children: Clone,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        PathIndexer {
+            iter: self.iter.clone(),
+            stack: self.stack.clone(),
+            path: self.path.clone(),
+            start: self.start.clone(),
+            step: self.step.clone(),
+        }
+    }
+}
+
+impl<I, N, T> Debug for PathIndexer<I, N, T>
+where
+    I: Debug,
+    N: Nested,
+    N::Children: Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PathIndexer")
+            .field("iter", &self.iter)
+            .field("stack", &self.stack)
+            .field("path", &self.path)
+            .field("start", &self.start)
+            .field("step", &self.step)
+            .finish()
+    }
+}
+
+impl<I, N, T> Iterator for PathIndexer<I, N, T>
+where
+    I: Iterator<Item = N>,
+    N: Nested,
+    T: IndexValue,
+{
+    type Item = (IndexPath<T>, N);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let node = match self.stack.last_mut() {
+                Some(children) => match children.next() {
+                    Some(node) => node,
+                    None => {
+                        self.stack.pop();
+                        self.path.pop();
+                        self.path.last_mut().unwrap().add_assign_ref(&self.step);
+                        continue;
+                    }
+                },
+                None => self.iter.next()?,
+            };
+            match node.into_children() {
+                Ok(children) => {
+                    self.stack.push(children);
+                    self.path.push(self.start.clone());
+                }
+                Err(leaf) => {
+                    let result = Some((self.path.clone(), leaf));
+                    self.path.last_mut().unwrap().add_assign_ref(&self.step);
+                    return result;
+                }
+            }
+        }
+    }
+}
+
+impl<I, N, T> FusedIterator for PathIndexer<I, N, T>
+where
+    I: FusedIterator<Item = N>,
+    N: Nested,
+    T: IndexValue,
+{}
@@ -0,0 +1,78 @@
+// Copyright 2025 Redglyph
+//
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::FusedIterator;
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields an independent count for each group of items, the group being given
+/// by a key closure, and the iteration item.
+///
+/// This `struct` is created by the [`index_by_group`](crate::IndexerIterator::index_by_group)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PerGroupIndexer<I, T, K, F> {
+    iter: I,
+    counters: HashMap<K, T>,
+    start: T,
+    step: T,
+    key: F,
+}
+
+impl<I, T, K, F> PerGroupIndexer<I, T, K, F> {
+    pub fn new(iter: I, start: T, step: T, key: F) -> PerGroupIndexer<I, T, K, F> {
+        PerGroupIndexer { iter, counters: HashMap::new(), start, step, key }
+    }
+
+    /// Returns the next index of each group seen so far.
+    pub fn counters(&self) -> &HashMap<K, T> {
+        &self.counters
+    }
+}
+
+impl<I, T, K, F> Iterator for PerGroupIndexer<I, T, K, F>
+where
+    I: Iterator,
+    T: IndexValue,
+    K: Hash + Eq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let counter = self.counters.entry((self.key)(&item)).or_insert_with(|| self.start.clone());
+        let result = Some((counter.clone(), item));
+        counter.add_assign_ref(&self.step);
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, K, F> ExactSizeIterator for PerGroupIndexer<I, T, K, F>
+where
+    I: ExactSizeIterator,
+    T: IndexValue,
+    K: Hash + Eq,
+    F: FnMut(&I::Item) -> K,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T, K, F> FusedIterator for PerGroupIndexer<I, T, K, F>
+where
+    I: FusedIterator,
+    T: IndexValue,
+    K: Hash + Eq,
+    F: FnMut(&I::Item) -> K,
+{}
@@ -0,0 +1,96 @@
+// Copyright 2025 Redglyph
+//
+
+use core::fmt::{Display, Formatter};
+use core::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// The syntax of the bind parameter placeholders in an SQL query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PlaceholderStyle {
+    /// `$1`, `$2`, ... (PostgreSQL)
+    Dollar,
+    /// `?`, `?`, ... (MySQL, SQLite, ODBC); the position is only given by the order of the placeholders.
+    Question,
+    /// `?1`, `?2`, ... (SQLite)
+    NumberedQuestion,
+    /// `:1`, `:2`, ... (Oracle, SQLite)
+    Colon,
+}
+
+/// A bind parameter placeholder of an SQL query, like `$3`.
+///
+/// The placeholder is only rendered when it's displayed; its position, starting at 1, is available
+/// even for the anonymous `?` style.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Placeholder {
+    position: usize,
+    style: PlaceholderStyle,
+}
+
+impl Placeholder {
+    /// Returns the position of the parameter, starting at 1.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the syntax of the placeholder.
+    pub fn style(&self) -> PlaceholderStyle {
+        self.style
+    }
+}
+
+impl Display for Placeholder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.style {
+            PlaceholderStyle::Dollar => write!(f, "${}", self.position),
+            PlaceholderStyle::Question => write!(f, "?"),
+            PlaceholderStyle::NumberedQuestion => write!(f, "?{}", self.position),
+            PlaceholderStyle::Colon => write!(f, ":{}", self.position),
+        }
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields an SQL [`Placeholder`] and the iteration item.
+///
+/// This `struct` is created by the [`index_placeholders`](crate::IndexerIterator::index_placeholders)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PlaceholderIndexer<I> {
+    iter: I,
+    next: Placeholder,
+}
+
+impl<I> PlaceholderIndexer<I> {
+    pub fn new(iter: I, style: PlaceholderStyle) -> PlaceholderIndexer<I> {
+        PlaceholderIndexer { iter, next: Placeholder { position: 1, style } }
+    }
+}
+
+impl<I: Iterator> Iterator for PlaceholderIndexer<I> {
+    type Item = (Placeholder, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let v = self.iter.next()?;
+        let result = Some((self.next, v));
+        self.next.position += 1;
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for PlaceholderIndexer<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for PlaceholderIndexer<I> {}
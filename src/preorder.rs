@@ -0,0 +1,67 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the preorder number and the depth of each node of a tree traversal,
+/// and the node itself, the depth being given by a closure.
+///
+/// This `struct` is created by the [`index_preorder`](crate::IndexerIterator::index_preorder)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PreorderIndexer<I, T, F> {
+    iter: I,
+    counter: T,
+    step: T,
+    depth: F,
+}
+
+impl<I, T, F> PreorderIndexer<I, T, F> {
+    pub fn new(iter: I, start: T, step: T, depth: F) -> PreorderIndexer<I, T, F> {
+        PreorderIndexer { iter, counter: start, step, depth }
+    }
+}
+
+impl<I, T, F> Iterator for PreorderIndexer<I, T, F>
+where
+    I: Iterator,
+    T: IndexValue,
+    F: FnMut(&I::Item) -> usize,
+{
+    type Item = ((T, usize), I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let depth = (self.depth)(&item);
+        let result = Some(((self.counter.clone(), depth), item));
+        self.counter.add_assign_ref(&self.step);
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, F> ExactSizeIterator for PreorderIndexer<I, T, F>
+where
+    I: ExactSizeIterator,
+    T: IndexValue,
+    F: FnMut(&I::Item) -> usize,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T, F> FusedIterator for PreorderIndexer<I, T, F>
+where
+    I: FusedIterator,
+    T: IndexValue,
+    F: FnMut(&I::Item) -> usize,
+{}
@@ -0,0 +1,80 @@
+// Copyright 2025 Redglyph
+//
+
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// An iterator over the cartesian product of two iterators which yields the pair of indices
+/// `(i, j)` and the pair of items.
+///
+/// This `struct` is created by the [`product_indexed`](crate::IndexerIterator::product_indexed)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ProductIndexer<I: Iterator, J, T> {
+    a: I,
+    a_cur: Option<(T, I::Item)>,
+    b_orig: J,
+    b: J,
+    i: T,
+    j: T,
+    start: T,
+    step: T,
+}
+
+impl<I: Iterator, J: Clone, T: Clone> ProductIndexer<I, J, T> {
+    pub fn new(a: I, b: J, start: T, step: T) -> ProductIndexer<I, J, T> {
+        ProductIndexer { a, a_cur: None, b_orig: b.clone(), b, i: start.clone(), j: start.clone(), start, step }
+    }
+}
+
+impl<I, J, T> Iterator for ProductIndexer<I, J, T>
+where
+    I: Iterator,
+    I::Item: Clone,
+    J: Iterator + Clone,
+    T: IndexValue,
+{
+    type Item = ((T, T), (I::Item, J::Item));
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((i, a)) = &self.a_cur {
+                if let Some(b) = self.b.next() {
+                    let result = Some(((i.clone(), self.j.clone()), (a.clone(), b)));
+                    self.j.add_assign_ref(&self.step);
+                    return result;
+                }
+            }
+            let a = self.a.next()?;
+            self.a_cur = Some((self.i.clone(), a));
+            self.i.add_assign_ref(&self.step);
+            self.b = self.b_orig.clone();
+            self.j = self.start.clone();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lo, a_hi) = self.a.size_hint();
+        let (b_lo, b_hi) = self.b_orig.size_hint();
+        let (cur_lo, cur_hi) = match self.a_cur {
+            Some(_) => self.b.size_hint(),
+            None => (0, Some(0)),
+        };
+        let lo = a_lo.saturating_mul(b_lo).saturating_add(cur_lo);
+        let hi = match (a_hi, b_hi, cur_hi) {
+            (Some(a), Some(b), Some(cur)) => a.checked_mul(b).and_then(|n| n.checked_add(cur)),
+            _ => None,
+        };
+        (lo, hi)
+    }
+}
+
+impl<I, J, T> ExactSizeIterator for ProductIndexer<I, J, T>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+    J: ExactSizeIterator + Clone,
+    T: IndexValue,
+{}
@@ -0,0 +1,146 @@
+// Copyright 2025 Redglyph
+//
+
+use core::fmt::{Display, Formatter, Write};
+use core::iter::FusedIterator;
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// An index label in a given radix, zero-padded to a minimum number of digits, like `0x00A3`.
+///
+/// The radices 2, 8 and 16 are prefixed by `0b`, `0o` and `0x`, respectively; the digits above 9
+/// are uppercase letters, and negative values are preceded by a minus sign, like `-0x0A`. The label
+/// is only rendered when it's displayed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RadixLabel<T> {
+    value: T,
+    radix: u32,
+    width: usize,
+}
+
+impl<T: Copy> RadixLabel<T> {
+    /// Returns the value of the index.
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the radix.
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+}
+
+impl<T> RadixLabel<T> {
+    /// Writes the prefix of the radix, then the digits of `n`, zero-padded to `self.width`.
+    fn write_digits(&self, f: &mut Formatter<'_>, negative: bool, mut n: u128) -> core::fmt::Result {
+        if negative {
+            f.write_char('-')?;
+        }
+        match self.radix {
+            2 => f.write_str("0b")?,
+            8 => f.write_str("0o")?,
+            16 => f.write_str("0x")?,
+            _ => {}
+        }
+        // enough for u128::MAX in base 2
+        let mut digits = [0_u8; 128];
+        let mut len = 0;
+        loop {
+            let d = (n % self.radix as u128) as u32;
+            digits[len] = char::from_digit(d, self.radix).unwrap().to_ascii_uppercase() as u8;
+            len += 1;
+            n /= self.radix as u128;
+            if n == 0 {
+                break;
+            }
+        }
+        for _ in len..self.width {
+            f.write_char('0')?;
+        }
+        for &d in digits[..len].iter().rev() {
+            f.write_char(d as char)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_radix_display {
+    (unsigned: $($u:ty),*; signed: $($s:ty),*) => {
+        $(
+            impl Display for RadixLabel<$u> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    self.write_digits(f, false, self.value as u128)
+                }
+            }
+        )*
+        $(
+            impl Display for RadixLabel<$s> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    self.write_digits(f, self.value < 0, self.value.unsigned_abs() as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_display!(unsigned: u8, u16, u32, u64, u128, usize; signed: i8, i16, i32, i64, i128, isize);
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields a [`RadixLabel`] and the iteration item.
+///
+/// This `struct` is created by the [`index_radix`](crate::IndexerIterator::index_radix)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RadixIndexer<I, T> {
+    iter: I,
+    counter: T,
+    step: T,
+    radix: u32,
+    width: usize,
+}
+
+impl<I, T> RadixIndexer<I, T> {
+    pub fn new(iter: I, start: T, step: T, radix: u32, width: usize) -> RadixIndexer<I, T> {
+        assert!((2..=36).contains(&radix), "the radix must be between 2 and 36");
+        RadixIndexer { iter, counter: start, step, radix, width }
+    }
+}
+
+impl<I, T> Iterator for RadixIndexer<I, T>
+where
+    I: Iterator,
+    T: Copy + IndexValue,
+{
+    type Item = (RadixLabel<T>, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let v = self.iter.next()?;
+        let result = Some((RadixLabel { value: self.counter, radix: self.radix, width: self.width }, v));
+        self.counter.add_assign_ref(&self.step);
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for RadixIndexer<I, T>
+where
+    I: ExactSizeIterator,
+    T: Copy + IndexValue,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for RadixIndexer<I, T>
+where
+    I: FusedIterator,
+    T: Copy + IndexValue,
+{}
@@ -0,0 +1,76 @@
+// Copyright 2025 Redglyph
+//
+
+use tokio::sync::mpsc::{Receiver, UnboundedReceiver};
+use tokio_stream::wrappers::{ReceiverStream, UnboundedReceiverStream};
+use futures_core::Stream;
+use crate::StreamIndexer;
+
+//------------------------------------------------------------------------------
+
+/// An extension trait which provides the indexing methods of [`IndexerStreamExt`](crate::IndexerStreamExt)
+/// directly on the receivers of the `tokio` channels, which are wrapped in their `tokio_stream::wrappers`
+/// stream.
+///
+/// The other `tokio_stream::wrappers` types are streams, so they can be indexed with
+/// [`IndexerStreamExt`](crate::IndexerStreamExt). In both cases, the indexed stream has the size hint
+/// of the wrapper, which gives the number of pending messages.
+///
+/// This trait requires the `tokio` feature.
+///
+/// # Examples
+///
+/// ```
+/// use futures::{executor::block_on, StreamExt};
+/// use iter_index::IndexedReceiver;
+///
+/// let (tx, rx) = tokio::sync::mpsc::channel(8);
+/// for job in ["parse", "check", "emit"] {
+///     tx.try_send(job).unwrap();
+/// }
+/// drop(tx);
+/// let result = block_on(rx.index_start::<u32>(1).collect::<Vec<_>>());
+///
+/// assert_eq!(result, vec![(1, "parse"), (2, "check"), (3, "emit")]);
+/// ```
+pub trait IndexedReceiver: Sized {
+    /// The stream which wraps the receiver.
+    type Stream: Stream;
+
+    /// Wraps the receiver in its stream.
+    fn into_stream(self) -> Self::Stream;
+
+    /// Creates a stream which gives an index of each message as well as the message itself;
+    /// see [`IndexerIterator::index`](crate::IndexerIterator::index).
+    fn index<T>(self) -> StreamIndexer<Self::Stream, T> where u8: Into<T> {
+        StreamIndexer::new(self.into_stream(), 0.into(), 1.into())
+    }
+
+    /// Creates a stream which gives an index of each message as well as the message itself;
+    /// see [`IndexerIterator::index_start`](crate::IndexerIterator::index_start).
+    fn index_start<T>(self, start: T) -> StreamIndexer<Self::Stream, T> where u8: Into<T> {
+        StreamIndexer::new(self.into_stream(), start, 1.into())
+    }
+
+    /// Creates a stream which gives an index of each message as well as the message itself;
+    /// see [`IndexerIterator::index_step`](crate::IndexerIterator::index_step).
+    fn index_step<T>(self, start: T, step: T) -> StreamIndexer<Self::Stream, T> {
+        StreamIndexer::new(self.into_stream(), start, step)
+    }
+}
+
+impl<V> IndexedReceiver for Receiver<V> {
+    type Stream = ReceiverStream<V>;
+
+    fn into_stream(self) -> Self::Stream {
+        ReceiverStream::new(self)
+    }
+}
+
+impl<V> IndexedReceiver for UnboundedReceiver<V> {
+    type Stream = UnboundedReceiverStream<V>;
+
+    fn into_stream(self) -> Self::Stream {
+        UnboundedReceiverStream::new(self)
+    }
+}
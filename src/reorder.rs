@@ -0,0 +1,110 @@
+// Copyright 2025 Redglyph
+//
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::iter::FusedIterator;
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// The error yielded by [`Reorder`] when the items can't be put back in order.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReorderError<T> {
+    /// The item with this index is missing: either the buffer is full and it still hasn't arrived,
+    /// or the source iterator ended without it.
+    Gap(T),
+    /// An item with this index has already been yielded, or is already pending.
+    Duplicate(T),
+}
+
+impl<T: Display> Display for ReorderError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReorderError::Gap(i) => write!(f, "missing item at index {i}"),
+            ReorderError::Duplicate(i) => write!(f, "duplicate item at index {i}"),
+        }
+    }
+}
+
+impl<T: Debug + Display> Error for ReorderError<T> {}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that takes the indexed items of the source iterator in any order, and yields them
+/// in the order of their index.
+///
+/// This `struct` is created by the [`reorder_by_index`](crate::IndexerIterator::reorder_by_index)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Reorder<I, T, V> {
+    iter: I,
+    pending: BTreeMap<T, V>,
+    expected: T,
+    step: T,
+    capacity: usize,
+    failed: bool,
+}
+
+impl<I, T: Ord, V> Reorder<I, T, V> {
+    pub fn new(iter: I, start: T, step: T, capacity: usize) -> Reorder<I, T, V> {
+        Reorder { iter, pending: BTreeMap::new(), expected: start, step, capacity, failed: false }
+    }
+
+    fn fail(&mut self, error: ReorderError<T>) -> Option<Result<(T, V), ReorderError<T>>> {
+        self.failed = true;
+        Some(Err(error))
+    }
+}
+
+impl<I, T, V> Iterator for Reorder<I, T, V>
+where
+    I: Iterator<Item = (T, V)>,
+    T: IndexValue + Ord,
+{
+    type Item = Result<(T, V), ReorderError<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let item = loop {
+            if let Some(v) = self.pending.remove(&self.expected) {
+                break (self.expected.clone(), v);
+            }
+            match self.iter.next() {
+                Some((i, v)) if i == self.expected => break (i, v),
+                Some((i, _)) if i < self.expected || self.pending.contains_key(&i) => {
+                    return self.fail(ReorderError::Duplicate(i));
+                }
+                Some((i, v)) => {
+                    if self.pending.len() == self.capacity {
+                        return self.fail(ReorderError::Gap(self.expected.clone()));
+                    }
+                    self.pending.insert(i, v);
+                }
+                None if self.pending.is_empty() => return None,
+                None => return self.fail(ReorderError::Gap(self.expected.clone())),
+            }
+        };
+        self.expected.add_assign_ref(&self.step);
+        Some(Ok(item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.failed {
+            return (0, Some(0));
+        }
+        let (_, hi) = self.iter.size_hint();
+        (0, hi.and_then(|hi| hi.checked_add(self.pending.len())))
+    }
+}
+
+impl<I, T, V> FusedIterator for Reorder<I, T, V>
+where
+    I: FusedIterator<Item = (T, V)>,
+    T: IndexValue + Ord,
+{}
@@ -0,0 +1,63 @@
+// Copyright 2025 Redglyph
+//
+
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the current count and the iteration item, restarting the count
+/// each time a predicate matches an item.
+///
+/// This `struct` is created by the [`index_reset_when`](crate::IndexerIterator::index_reset_when) and
+/// [`index_step_reset_when`](crate::IndexerIterator::index_step_reset_when) methods on
+/// [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ResetIndexer<I, T, P> {
+    iter: I,
+    start: T,
+    counter: T,
+    step: T,
+    predicate: P,
+}
+
+impl<I, T: Clone, P> ResetIndexer<I, T, P> {
+    pub fn new(iter: I, start: T, step: T, predicate: P) -> ResetIndexer<I, T, P> {
+        ResetIndexer { iter, counter: start.clone(), start, step, predicate }
+    }
+}
+
+impl<I, T, P> Iterator for ResetIndexer<I, T, P>
+where
+    I: Iterator,
+    T: IndexValue,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        if (self.predicate)(&item) {
+            self.counter = self.start.clone();
+        }
+        let result = Some((self.counter.clone(), item));
+        self.counter.add_assign_ref(&self.step);
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, P> ExactSizeIterator for ResetIndexer<I, T, P>
+where
+    I: ExactSizeIterator,
+    T: IndexValue,
+    P: FnMut(&I::Item) -> bool,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
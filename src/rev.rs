@@ -0,0 +1,86 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use crate::IndexValue;
+use crate::value::add_steps;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the items of an [`Indexer`](crate::Indexer) in reverse order, with
+/// the indices they have in the forward direction, using a length given by the caller.
+///
+/// This `struct` is created by the [`rev_with_len`](crate::Indexer::rev_with_len) method on
+/// [`Indexer`](crate::Indexer).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RevIndexer<I, T> {
+    iter: I,
+    counter: T,
+    step: T,
+    len: usize,
+}
+
+impl<I, T> RevIndexer<I, T> {
+    pub fn new(iter: I, start: T, step: T, len: usize) -> RevIndexer<I, T> {
+        RevIndexer { iter, counter: start, step, len }
+    }
+}
+
+impl<I, T> RevIndexer<I, T>
+where
+    T: IndexValue,
+{
+    /// Takes one item off the remaining length, and returns the index of the last remaining item.
+    fn take_last_index(&mut self) -> T {
+        self.len = self.len.checked_sub(1).expect("the source iterator has more items than the given length");
+        add_steps(&self.counter, &self.step, self.len)
+    }
+}
+
+impl<I, T> Iterator for RevIndexer<I, T>
+where
+    I: DoubleEndedIterator,
+    T: IndexValue,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        Some((self.take_last_index(), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<I, T> DoubleEndedIterator for RevIndexer<I, T>
+where
+    I: DoubleEndedIterator,
+    T: IndexValue,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.len = self.len.checked_sub(1).expect("the source iterator has more items than the given length");
+        let next = self.counter.add_ref(&self.step);
+        Some((core::mem::replace(&mut self.counter, next), item))
+    }
+}
+
+impl<I, T> ExactSizeIterator for RevIndexer<I, T>
+where
+    I: DoubleEndedIterator,
+    T: IndexValue,
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<I, T> FusedIterator for RevIndexer<I, T>
+where
+    I: DoubleEndedIterator + FusedIterator,
+    T: IndexValue,
+{}
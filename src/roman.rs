@@ -0,0 +1,95 @@
+// Copyright 2025 Redglyph
+//
+
+use core::fmt::{Display, Formatter};
+use core::iter::FusedIterator;
+use crate::LetterCase;
+
+//------------------------------------------------------------------------------
+
+/// A Roman numeral label: `I`, `II`, `III`, `IV`, ...
+///
+/// The numeral is only rendered when it's displayed. Values above 3999 are rendered with as many `M`
+/// as needed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Roman {
+    value: usize,
+    case: LetterCase,
+}
+
+impl Roman {
+    /// Returns the value of the numeral, starting at 1.
+    pub fn value(&self) -> usize {
+        self.value
+    }
+
+    /// Returns the case of the letters.
+    pub fn case(&self) -> LetterCase {
+        self.case
+    }
+}
+
+impl Display for Roman {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        const SYMBOLS: [(usize, &str, &str); 13] = [
+            (1000, "M", "m"), (900, "CM", "cm"), (500, "D", "d"), (400, "CD", "cd"),
+            (100, "C", "c"), (90, "XC", "xc"), (50, "L", "l"), (40, "XL", "xl"),
+            (10, "X", "x"), (9, "IX", "ix"), (5, "V", "v"), (4, "IV", "iv"), (1, "I", "i"),
+        ];
+        let mut n = self.value;
+        for (value, upper, lower) in SYMBOLS {
+            let symbol = match self.case {
+                LetterCase::Upper => upper,
+                LetterCase::Lower => lower,
+            };
+            while n >= value {
+                f.write_str(symbol)?;
+                n -= value;
+            }
+        }
+        Ok(())
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields a [`Roman`] numeral and the iteration item.
+///
+/// This `struct` is created by the [`index_roman`](crate::IndexerIterator::index_roman)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RomanIndexer<I> {
+    iter: I,
+    next: Roman,
+}
+
+impl<I> RomanIndexer<I> {
+    pub fn new(iter: I, case: LetterCase) -> RomanIndexer<I> {
+        RomanIndexer { iter, next: Roman { value: 1, case } }
+    }
+}
+
+impl<I: Iterator> Iterator for RomanIndexer<I> {
+    type Item = (Roman, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let v = self.iter.next()?;
+        let result = Some((self.next, v));
+        self.next.value += 1;
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for RomanIndexer<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for RomanIndexer<I> {}
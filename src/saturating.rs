@@ -0,0 +1,84 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// The saturating addition of an index type, which clamps the result at the numeric bounds of the
+/// type instead of overflowing.
+///
+/// The trait is implemented for the primitive integer types.
+pub trait SaturatingIndex: Sized {
+    /// Returns `self + rhs`, clamped at the numeric bounds of the type.
+    fn saturating_add(&self, rhs: &Self) -> Self;
+}
+
+macro_rules! impl_saturating_index {
+    ($($t:ty),*) => {$(
+        impl SaturatingIndex for $t {
+            #[inline]
+            fn saturating_add(&self, rhs: &Self) -> Self {
+                <$t>::saturating_add(*self, *rhs)
+            }
+        }
+    )*};
+}
+
+impl_saturating_index!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the current count, clamped at the numeric bounds of the index type,
+/// and the iteration item.
+///
+/// This `struct` is created by the [`index_saturating`](crate::IndexerIterator::index_saturating)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SaturatingIndexer<I, T> {
+    iter: I,
+    counter: T,
+    step: T,
+}
+
+impl<I, T> SaturatingIndexer<I, T> {
+    pub fn new(iter: I, start: T, step: T) -> SaturatingIndexer<I, T> {
+        SaturatingIndexer { iter, counter: start, step }
+    }
+}
+
+impl<I, T> Iterator for SaturatingIndexer<I, T>
+where
+    I: Iterator,
+    T: SaturatingIndex,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let next = self.counter.saturating_add(&self.step);
+        Some((core::mem::replace(&mut self.counter, next), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for SaturatingIndexer<I, T>
+where
+    I: ExactSizeIterator,
+    T: SaturatingIndex,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for SaturatingIndexer<I, T>
+where
+    I: FusedIterator,
+    T: SaturatingIndex,
+{}
@@ -0,0 +1,67 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+use core::ops::MulAssign;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields an index multiplied by a factor at each item, and the iteration item.
+///
+/// This `struct` is created by the [`index_scale`](crate::IndexerIterator::index_scale)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ScaleIndexer<I, T> {
+    iter: I,
+    counter: T,
+    factor: T,
+    first: bool,
+}
+
+impl<I, T> ScaleIndexer<I, T> {
+    pub fn new(iter: I, start: T, factor: T) -> ScaleIndexer<I, T> {
+        ScaleIndexer { iter, counter: start, factor, first: true }
+    }
+}
+
+impl<I, T> Iterator for ScaleIndexer<I, T>
+where
+    I: Iterator,
+    T: Clone + for<'a> MulAssign<&'a T>,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        // the index is only multiplied when another item comes, so the last index
+        // can be the largest power that fits in the type
+        if self.first {
+            self.first = false;
+        } else {
+            self.counter *= &self.factor;
+        }
+        Some((self.counter.clone(), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for ScaleIndexer<I, T>
+where
+    I: ExactSizeIterator,
+    T: Clone + for<'a> MulAssign<&'a T>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for ScaleIndexer<I, T>
+where
+    I: FusedIterator,
+    T: Clone + for<'a> MulAssign<&'a T>,
+{}
@@ -0,0 +1,79 @@
+// Copyright 2025 Redglyph
+//
+
+use core::num::Wrapping;
+
+//------------------------------------------------------------------------------
+
+/// Serial number arithmetic, as defined in [RFC 1982](https://www.rfc-editor.org/rfc/rfc1982), for
+/// the indices which wrap around the range of their type, like the sequence numbers of TCP or RTP.
+///
+/// The comparisons are valid as long as the two values are less than half the range of the type apart;
+/// when they're exactly half the range apart, neither is smaller than the other.
+///
+/// The trait is implemented for the unsigned integer types, and for `Wrapping<T>` of those types, so it
+/// can be used on the indices given by [`index_wrapping`](crate::IndexerIterator::index_wrapping).
+///
+/// # Examples
+///
+/// ```
+/// use iter_index::SerialNumber;
+///
+/// // a sequence number which has wrapped around after 65535
+/// let (a, b) = (65534_u16, 1_u16);
+///
+/// assert!(a.seq_lt(b));
+/// assert!(b.seq_gt(a));
+/// assert_eq!(a.seq_distance(b), 3);
+/// assert_eq!(b.seq_distance(a), -3);
+/// ```
+pub trait SerialNumber: Copy {
+    /// The signed type of the distance between two serial numbers.
+    type Distance;
+
+    /// Returns the signed distance from `self` to `other`, which is positive if `other` comes after `self`.
+    fn seq_distance(self, other: Self) -> Self::Distance;
+
+    /// Returns `true` if `self` comes before `other`.
+    fn seq_lt(self, other: Self) -> bool;
+
+    /// Returns `true` if `self` comes after `other`.
+    fn seq_gt(self, other: Self) -> bool {
+        other.seq_lt(self)
+    }
+}
+
+macro_rules! impl_serial_number {
+    ($($t:ty => $d:ty),*) => {$(
+        impl SerialNumber for $t {
+            type Distance = $d;
+
+            #[inline]
+            fn seq_distance(self, other: Self) -> $d {
+                other.wrapping_sub(self) as $d
+            }
+
+            #[inline]
+            fn seq_lt(self, other: Self) -> bool {
+                // the distance is <d>::MIN when the values are half the range apart: undefined
+                self.seq_distance(other) > 0
+            }
+        }
+    )*};
+}
+
+impl_serial_number!(u8 => i8, u16 => i16, u32 => i32, u64 => i64, u128 => i128, usize => isize);
+
+impl<T: SerialNumber> SerialNumber for Wrapping<T> {
+    type Distance = T::Distance;
+
+    #[inline]
+    fn seq_distance(self, other: Self) -> T::Distance {
+        self.0.seq_distance(other.0)
+    }
+
+    #[inline]
+    fn seq_lt(self, other: Self) -> bool {
+        self.0.seq_lt(other.0)
+    }
+}
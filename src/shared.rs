@@ -0,0 +1,124 @@
+// Copyright 2025 Redglyph
+//
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// A counter handle that can be shared by several indexed iterators, so that the numbering continues
+/// from one iterator to the next.
+///
+/// Cloning a `SharedIndex` gives another handle to the same counter. It is meant for single-threaded
+/// use and doesn't implement `Send` or `Sync`.
+///
+/// # Examples
+///
+/// ```
+/// use iter_index::{IndexerIterator, SharedIndex};
+///
+/// let files = vec![vec!["a", "b"], vec!["c"], vec!["d", "e"]];
+/// let counter = SharedIndex::<u32>::new(1, 1);
+/// let mut result = Vec::new();
+/// for file in files {
+///     for (i, line) in file.into_iter().index_shared(&counter) {
+///         result.push(format!("{i}:{line}"));
+///     }
+/// }
+///
+/// assert_eq!(result, vec!["1:a", "2:b", "3:c", "4:d", "5:e"]);
+/// assert_eq!(counter.get(), 6);
+/// ```
+#[derive(Debug)]
+pub struct SharedIndex<T> {
+    state: Rc<SharedState<T>>,
+}
+
+#[derive(Debug)]
+struct SharedState<T> {
+    counter: RefCell<T>,
+    step: T,
+}
+
+impl<T> SharedIndex<T> {
+    /// Creates a shared counter starting at `start` and incremented by `step` for each item.
+    pub fn new(start: T, step: T) -> SharedIndex<T> {
+        SharedIndex { state: Rc::new(SharedState { counter: RefCell::new(start), step }) }
+    }
+
+    /// Sets the value of the next index.
+    pub fn set(&self, value: T) {
+        *self.state.counter.borrow_mut() = value;
+    }
+}
+
+impl<T: Clone> SharedIndex<T> {
+    /// Returns the value of the next index.
+    pub fn get(&self) -> T {
+        self.state.counter.borrow().clone()
+    }
+}
+
+impl<T: IndexValue> SharedIndex<T> {
+    /// Returns the value of the next index and moves the counter by one step.
+    pub fn next_index(&self) -> T {
+        let mut counter = self.state.counter.borrow_mut();
+        let value = counter.clone();
+        counter.add_assign_ref(&self.state.step);
+        value
+    }
+}
+
+impl<T> Clone for SharedIndex<T> {
+    fn clone(&self) -> Self {
+        SharedIndex { state: Rc::clone(&self.state) }
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields an index taken from a [`SharedIndex`] and the iteration item.
+///
+/// This `struct` is created by the [`index_shared`](crate::IndexerIterator::index_shared)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SharedIndexer<I, T> {
+    iter: I,
+    index: SharedIndex<T>,
+}
+
+impl<I, T> SharedIndexer<I, T> {
+    pub fn new(iter: I, index: SharedIndex<T>) -> SharedIndexer<I, T> {
+        SharedIndexer { iter, index }
+    }
+}
+
+impl<I, T> Iterator for SharedIndexer<I, T>
+where
+    I: Iterator,
+    T: IndexValue,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        Some((self.index.next_index(), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for SharedIndexer<I, T>
+where
+    I: ExactSizeIterator,
+    T: IndexValue,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// The addition of a signed delta `D` to an unsigned index type.
+///
+/// The trait is implemented for the unsigned primitive integer types, with the signed type of the
+/// same size.
+pub trait SignedStep<D> {
+    /// Returns `self + delta`.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// Like the arithmetic operators, the method panics on overflow in debug mode.
+    fn add_signed(&self, delta: &D) -> Self;
+}
+
+macro_rules! impl_signed_step {
+    ($($t:ty => $d:ty),*) => {$(
+        impl SignedStep<$d> for $t {
+            #[inline]
+            fn add_signed(&self, delta: &$d) -> Self {
+                if *delta >= 0 {
+                    *self + *delta as $t
+                } else {
+                    *self - delta.unsigned_abs()
+                }
+            }
+        }
+    )*};
+}
+
+impl_signed_step!(u8 => i8, u16 => i16, u32 => i32, u64 => i64, u128 => i128, usize => isize);
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the current count, which is incremented by a signed step, and the
+/// iteration item.
+///
+/// This `struct` is created by the [`index_step_signed`](crate::IndexerIterator::index_step_signed)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SignedStepIndexer<I, T, D> {
+    iter: I,
+    counter: T,
+    step: D,
+    first: bool,
+}
+
+impl<I, T, D> SignedStepIndexer<I, T, D> {
+    pub fn new(iter: I, start: T, step: D) -> SignedStepIndexer<I, T, D> {
+        SignedStepIndexer { iter, counter: start, step, first: true }
+    }
+}
+
+impl<I, T, D> Iterator for SignedStepIndexer<I, T, D>
+where
+    I: Iterator,
+    T: Clone + SignedStep<D>,
+{
+    type Item = (T, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        // the counter is only moved when another item comes, so it can count down to 0
+        if self.first {
+            self.first = false;
+        } else {
+            self.counter = self.counter.add_signed(&self.step);
+        }
+        Some((self.counter.clone(), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, D> ExactSizeIterator for SignedStepIndexer<I, T, D>
+where
+    I: ExactSizeIterator,
+    T: Clone + SignedStep<D>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T, D> FusedIterator for SignedStepIndexer<I, T, D>
+where
+    I: FusedIterator,
+    T: Clone + SignedStep<D>,
+{}
@@ -0,0 +1,160 @@
+// Copyright 2025 Redglyph
+//
+
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// The error yielded by [`CheckSorted`] and [`CheckSortedByKey`] when an item is out of order.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnsortedError<T> {
+    /// The index of the first item which is out of order.
+    pub index: T,
+}
+
+impl<T: Display> Display for UnsortedError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "item at index {} is out of order", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Debug + Display> std::error::Error for UnsortedError<T> {}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that passes the indexed items of the source iterator through while they're sorted
+/// according to a comparison function, and which yields an [`UnsortedError`] at the first item
+/// which isn't.
+///
+/// This `struct` is created by the [`check_sorted_by`](crate::IndexerIterator::check_sorted_by)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CheckSorted<I, V, F> {
+    iter: I,
+    prev: Option<V>,
+    compare: F,
+    done: bool,
+}
+
+impl<I, V, F> CheckSorted<I, V, F> {
+    pub fn new(iter: I, compare: F) -> CheckSorted<I, V, F> {
+        CheckSorted { iter, prev: None, compare, done: false }
+    }
+}
+
+impl<I, T, V, F> Iterator for CheckSorted<I, V, F>
+where
+    I: Iterator<Item = (T, V)>,
+    V: Clone,
+    F: FnMut(&V, &V) -> Ordering,
+{
+    type Item = Result<(T, V), UnsortedError<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let (i, v) = match self.iter.next() {
+            Some(item) => item,
+            None => {
+                self.done = true;
+                return None;
+            }
+        };
+        if let Some(prev) = &self.prev {
+            if (self.compare)(prev, &v) == Ordering::Greater {
+                self.done = true;
+                return Some(Err(UnsortedError { index: i }));
+            }
+        }
+        self.prev = Some(v.clone());
+        Some(Ok((i, v)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<I, T, V, F> FusedIterator for CheckSorted<I, V, F>
+where
+    I: Iterator<Item = (T, V)>,
+    V: Clone,
+    F: FnMut(&V, &V) -> Ordering,
+{}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that passes the indexed items of the source iterator through while they're sorted
+/// according to a key, and which yields an [`UnsortedError`] at the first item which isn't.
+///
+/// This `struct` is created by the [`check_sorted_by_key`](crate::IndexerIterator::check_sorted_by_key)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CheckSortedByKey<I, K, F> {
+    iter: I,
+    prev: Option<K>,
+    key: F,
+    done: bool,
+}
+
+impl<I, K, F> CheckSortedByKey<I, K, F> {
+    pub fn new(iter: I, key: F) -> CheckSortedByKey<I, K, F> {
+        CheckSortedByKey { iter, prev: None, key, done: false }
+    }
+}
+
+impl<I, T, V, K, F> Iterator for CheckSortedByKey<I, K, F>
+where
+    I: Iterator<Item = (T, V)>,
+    K: Ord,
+    F: FnMut(&V) -> K,
+{
+    type Item = Result<(T, V), UnsortedError<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let (i, v) = match self.iter.next() {
+            Some(item) => item,
+            None => {
+                self.done = true;
+                return None;
+            }
+        };
+        let key = (self.key)(&v);
+        if matches!(&self.prev, Some(prev) if *prev > key) {
+            self.done = true;
+            return Some(Err(UnsortedError { index: i }));
+        }
+        self.prev = Some(key);
+        Some(Ok((i, v)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<I, T, V, K, F> FusedIterator for CheckSortedByKey<I, K, F>
+where
+    I: Iterator<Item = (T, V)>,
+    K: Ord,
+    F: FnMut(&V) -> K,
+{}
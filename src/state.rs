@@ -0,0 +1,45 @@
+// Copyright 2025 Redglyph
+//
+
+use serde::{Deserialize, Serialize};
+use serde::ser::{SerializeStruct, Serializer};
+use crate::Indexer;
+
+//------------------------------------------------------------------------------
+
+/// A snapshot of the counter of an [`Indexer`]: the start value, the index of the next item, and the step.
+///
+/// The snapshot is taken with [`Indexer::counter_state`] and can be serialized, so that a long-running
+/// indexed job can be checkpointed and resumed later with [`index_resume`](crate::IndexerIterator::index_resume),
+/// the source iterator being supplied again on resume. An `Indexer` is serialized as its `CounterState`,
+/// so it can be deserialized as a `CounterState`, too.
+///
+/// This type requires the `serde` feature.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CounterState<T> {
+    pub start: T,
+    pub counter: T,
+    pub step: T,
+}
+
+impl<I, T: Clone> Indexer<I, T> {
+    /// Returns a snapshot of the start value, of the counter, which gives the index of the next item,
+    /// and of the step.
+    ///
+    /// This method requires the `serde` feature.
+    pub fn counter_state(&self) -> CounterState<T> {
+        let start = self.start.as_ref().unwrap_or(&self.counter).clone();
+        CounterState { start, counter: self.counter.clone(), step: self.step.clone() }
+    }
+}
+
+impl<I, T: Serialize> Serialize for Indexer<I, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // same representation as CounterState
+        let mut state = serializer.serialize_struct("CounterState", 3)?;
+        state.serialize_field("start", self.start.as_ref().unwrap_or(&self.counter))?;
+        state.serialize_field("counter", &self.counter)?;
+        state.serialize_field("step", &self.step)?;
+        state.end()
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2025 Redglyph
+//
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::{FusedStream, Stream};
+use crate::IndexValue;
+
+//------------------------------------------------------------------------------
+
+/// An extension trait which provides the indexing methods of [`IndexerIterator`](crate::IndexerIterator)
+/// on asynchronous streams.
+///
+/// This trait requires the `stream` feature.
+///
+/// # Examples
+///
+/// ```
+/// use futures::{executor::block_on, stream, StreamExt};
+/// use iter_index::IndexerStreamExt;
+///
+/// let items = stream::iter(vec!["a", "b", "c"]);
+/// let result = block_on(items.index_step::<u32>(100, 10).collect::<Vec<_>>());
+///
+/// assert_eq!(result, vec![(100, "a"), (110, "b"), (120, "c")]);
+/// ```
+pub trait IndexerStreamExt: Stream {
+    /// Creates a stream which gives an index of each value as well as the value itself;
+    /// see [`IndexerIterator::index`](crate::IndexerIterator::index).
+    fn index<T>(self) -> StreamIndexer<Self, T> where Self: Sized, u8: Into<T> {
+        StreamIndexer::new(self, 0.into(), 1.into())
+    }
+
+    /// Creates a stream which gives an index of each value as well as the value itself;
+    /// see [`IndexerIterator::index_start`](crate::IndexerIterator::index_start).
+    fn index_start<T>(self, start: T) -> StreamIndexer<Self, T> where Self: Sized, u8: Into<T> {
+        StreamIndexer::new(self, start, 1.into())
+    }
+
+    /// Creates a stream which gives an index of each value as well as the value itself;
+    /// see [`IndexerIterator::index_step`](crate::IndexerIterator::index_step).
+    fn index_step<T>(self, start: T, step: T) -> StreamIndexer<Self, T> where Self: Sized {
+        StreamIndexer::new(self, start, step)
+    }
+}
+
+impl<S: Stream> IndexerStreamExt for S {}
+
+//------------------------------------------------------------------------------
+
+pin_project_lite::pin_project! {
+    /// A stream that yields the current count, with the generic type, and the item of the source stream.
+    ///
+    /// This `struct` is created by the methods of [`IndexerStreamExt`].
+    #[derive(Clone, Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct StreamIndexer<S, T> {
+        #[pin]
+        stream: S,
+        counter: T,
+        step: T,
+    }
+}
+
+impl<S, T> StreamIndexer<S, T> {
+    pub fn new(stream: S, start: T, step: T) -> StreamIndexer<S, T> {
+        StreamIndexer { stream, counter: start, step }
+    }
+}
+
+impl<S, T> Stream for StreamIndexer<S, T>
+where
+    S: Stream,
+    T: IndexValue,
+{
+    type Item = (T, S::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        match this.stream.poll_next(cx) {
+            Poll::Ready(Some(v)) => {
+                let next = this.counter.add_ref(this.step);
+                Poll::Ready(Some((core::mem::replace(this.counter, next), v)))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S, T> FusedStream for StreamIndexer<S, T>
+where
+    S: FusedStream,
+    T: IndexValue,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
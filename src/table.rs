@@ -0,0 +1,33 @@
+// Copyright 2025 Redglyph
+//
+
+use std::fmt::Display;
+
+//------------------------------------------------------------------------------
+
+/// Renders the indexed items as a two-column table, the index column being aligned to the right
+/// and the value column to the left.
+pub(crate) fn render<I, T, V>(iter: I, headers: [&str; 2]) -> String
+where
+    I: Iterator<Item = (T, V)>,
+    T: Display,
+    V: Display,
+{
+    let rows = iter.map(|(i, v)| (i.to_string(), v.to_string())).collect::<Vec<_>>();
+    let width = |header: &str, cells: &mut dyn Iterator<Item = &String>| {
+        cells.map(|s| s.chars().count()).fold(header.chars().count(), usize::max)
+    };
+    let w0 = width(headers[0], &mut rows.iter().map(|(i, _)| i));
+    let w1 = width(headers[1], &mut rows.iter().map(|(_, v)| v));
+    let mut table = String::new();
+    let mut push_row = |i: &str, v: &str| {
+        table.push_str(format!("{i:>w0$} | {v:w1$}").trim_end());
+        table.push('\n');
+    };
+    push_row(headers[0], headers[1]);
+    push_row(&"-".repeat(w0), &"-".repeat(w1));
+    for (i, v) in &rows {
+        push_row(i, v);
+    }
+    table
+}
@@ -1,7 +1,7 @@
-#![cfg(test)]
+#![cfg(all(test, feature = "std"))]
 
 use std::collections::HashMap;
-use crate::IndexerIterator;
+use crate::{IndexerIterator, SharedIndex};
 
 #[test]
 fn index() {
@@ -41,12 +41,19 @@ fn index_nth() {
     assert_eq!(result.nth(5), Some((160, 'g')));
 }
 
-#[should_panic(expected = "Cannot convert n into u8")]
 #[test]
-fn index_nth_error() {
-    let items = 1_u32 ..= 1000;
-    let mut result = items.index_step::<u8>(10, 2);
-    assert_eq!(result.nth(500), Some(((1010 & 255) as u8, 501_u32)));
+fn index_nth_large() {
+    // n doesn't fit in the index type, but the index does
+    let items = 1_u32 ..= 50000;
+    let mut result = items.index_start::<i16>(-30000);
+    assert_eq!(result.nth(40000), Some((10000, 40001_u32)));
+    assert_eq!(result.next(), Some((10001, 40002)));
+
+    let mut result = (0..1000).index_step::<u8>(7, 0);
+    assert_eq!(result.nth(999), Some((7, 999)));
+
+    let mut result = (0..50000).index_start::<i16>(-32000);
+    assert_eq!(result.nth_back(0), Some((17999, 49999)));
 }
 
 #[test]
@@ -73,3 +80,1503 @@ fn index_double_ended_iterator() {
     let mut result = items.into_iter().index_step::<i16>(100, 10);
     assert_eq!(result.nth_back(2), Some((110, "b")));
 }
+
+#[test]
+fn index_reset_when() {
+    let lines = vec!["# A", "a1", "a2", "", "# B", "b1", "# C"];
+    let result = lines.into_iter().index_reset_when::<u8, _>(|s| s.starts_with('#')).collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, "# A"), (1, "a1"), (2, "a2"), (3, ""), (0, "# B"), (1, "b1"), (0, "# C")]);
+
+    let bytes = [3_u8, 0xff, 1, 2, 0xff, 0xff, 5];
+    let result = bytes.iter().index_reset_when::<usize, _>(|&&b| b == 0xff).map(|(i, _)| i).collect::<Vec<_>>();
+    assert_eq!(result, vec![0, 0, 1, 2, 0, 0, 1]);
+
+    let result = (1..=7).index_step_reset_when::<i16, _>(-1, -1, |x| x % 3 == 0).map(|(i, _)| i).collect::<Vec<_>>();
+    assert_eq!(result, vec![-1, -2, -1, -2, -3, -1, -2]);
+}
+
+#[test]
+fn index_outline() {
+    let depths = vec![0, 1, 1, 2, 0, 2, 1, 0];
+    let result = depths.into_iter().index_outline::<u16, _>(|&d| d).map(|(o, _)| o.to_string()).collect::<Vec<_>>();
+    assert_eq!(result, vec!["1", "1.1", "1.2", "1.2.1", "2", "2.1.1", "2.2", "3"]);
+
+    let mut it = vec!["a", "b"].into_iter().index_outline::<u8, _>(|_| 1);
+    let (label, _) = it.next().unwrap();
+    assert_eq!(label.path(), &[1, 1]);
+    assert_eq!(label.depth(), 1);
+    assert_eq!(it.next().unwrap().0.into_path(), vec![1, 2]);
+}
+
+#[test]
+fn index_shared() {
+    let counter = SharedIndex::<i32>::new(10, -2);
+    let mut a = vec!["a", "b", "c"].into_iter().index_shared(&counter);
+    let mut b = vec!["x", "y"].into_iter().index_shared(&counter);
+    assert_eq!(a.next(), Some((10, "a")));
+    assert_eq!(b.next(), Some((8, "x")));
+    assert_eq!(a.next(), Some((6, "b")));
+    counter.set(100);
+    assert_eq!(b.next(), Some((100, "y")));
+    assert_eq!(b.next(), None);
+    assert_eq!(a.next(), Some((98, "c")));
+    assert_eq!(counter.get(), 96);
+}
+
+#[test]
+fn chain_indexed() {
+    let result = crate::chain_indexed::<u8, _>(vec![1..3, 0..0, 5..6, 7..9]).collect::<Vec<_>>();
+    assert_eq!(result, vec![((0, 0), 1), ((1, 0), 2), ((2, 2), 5), ((3, 3), 7), ((4, 3), 8)]);
+
+    let mut it = crate::chain_indexed::<u32, _>([vec!["a"], vec!["b", "c"]]);
+    assert_eq!(it.next(), Some(((0, 0), "a")));
+    assert_eq!(it.size_hint(), (0, None));
+    assert_eq!(it.next(), Some(((1, 1), "b")));
+    assert_eq!(it.size_hint(), (1, Some(1)));
+    assert_eq!(it.next(), Some(((2, 1), "c")));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn flatten_indexed() {
+    let lists = vec![vec![], vec![1, 2, 3], vec![], vec![4], vec![5, 6]];
+    let result = lists.into_iter().flatten_indexed::<u16>().collect::<Vec<_>>();
+    assert_eq!(result, vec![((1, 0), 1), ((1, 1), 2), ((1, 2), 3), ((3, 0), 4), ((4, 0), 5), ((4, 1), 6)]);
+
+    let result = (1..4).flat_map_indexed::<i64, _, _>(|n| 0..n).map(|(ij, _)| ij).collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, 0), (1, 0), (1, 1), (2, 0), (2, 1), (2, 2)]);
+}
+
+#[test]
+fn product_indexed() {
+    let mut it = (0..3).product_indexed::<u32, _>(['x', 'y']);
+    assert_eq!(it.len(), 6);
+    assert_eq!(it.next(), Some(((0, 0), (0, 'x'))));
+    assert_eq!(it.len(), 5);
+    assert_eq!(it.size_hint(), (5, Some(5)));
+    let rest = it.collect::<Vec<_>>();
+    assert_eq!(rest, vec![((0, 1), (0, 'y')), ((1, 0), (1, 'x')), ((1, 1), (1, 'y')), ((2, 0), (2, 'x')), ((2, 1), (2, 'y'))]);
+
+    assert_eq!((0..3).product_indexed::<u8, _>(0..0).count(), 0);
+    assert_eq!((0..0).product_indexed::<u8, _>(0..3).len(), 0);
+}
+
+#[test]
+fn with_throughput() {
+    use std::time::Duration;
+    let items = (0..4).inspect(|_| std::thread::sleep(Duration::from_millis(2))).with_throughput(Duration::ZERO);
+    let meter = items.meter();
+    let mut result = items.index::<u8>();
+    assert_eq!(meter.rate(), None);
+    assert_eq!(result.next(), Some((0, 0)));
+    let rate = meter.rate().unwrap();
+    assert!(rate > 0.0 && rate <= 500.0, "rate = {rate}");
+    assert_eq!(result.count(), 3);
+    assert_eq!(meter.count(), 4);
+}
+
+#[test]
+fn with_throughput_reporter_thread() {
+    use std::time::Duration;
+    let items = (0..100).with_throughput(Duration::ZERO);
+    let meter = items.meter();
+    let reporter = std::thread::spawn(move || {
+        while meter.count() < 100 {
+            std::thread::yield_now();
+        }
+        meter
+    });
+    assert_eq!(items.index::<u8>().count(), 100);
+    let meter = reporter.join().unwrap();
+    assert_eq!(meter.count(), 100);
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn record_metrics() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use metrics::{Counter, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString, Unit};
+
+    #[derive(Default)]
+    struct TestRecorder {
+        counter: Arc<AtomicU64>,
+        gauge: Arc<AtomicU64>,
+    }
+
+    impl Recorder for TestRecorder {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            assert_eq!(key.name(), "items");
+            Counter::from_arc(self.counter.clone())
+        }
+        fn register_gauge(&self, key: &Key, _: &Metadata<'_>) -> Gauge {
+            assert_eq!(key.name(), "index");
+            Gauge::from_arc(self.gauge.clone())
+        }
+        fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::noop()
+        }
+    }
+
+    let recorder = TestRecorder::default();
+    let result = metrics::with_local_recorder(&recorder, || {
+        (10..15).index_step::<u16>(100, 10)
+            .record_metrics("items")
+            .with_gauge("index", |(i, _)| f64::from(*i))
+            .take(4)
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(result, vec![(100, 10), (110, 11), (120, 12), (130, 13)]);
+    assert_eq!(recorder.counter.load(Ordering::Relaxed), 4);
+    assert_eq!(f64::from_bits(recorder.gauge.load(Ordering::Relaxed)), 130.0);
+}
+
+#[test]
+fn on_every() {
+    let mut calls = Vec::new();
+    let it = "abcdefg".chars().index_step::<i16>(0, 5).on_every(3, |&i, &c| calls.push((i, c)));
+    assert_eq!(it.size_hint(), "abcdefg".chars().size_hint());
+    let result = it.map(|(_, c)| c).collect::<String>();
+    assert_eq!(result, "abcdefg");
+    assert_eq!(calls, vec![(10, 'c'), (25, 'f')]);
+}
+
+#[should_panic(expected = "n must be greater than 0")]
+#[test]
+fn on_every_zero() {
+    let _ = (0..3).index::<u8>().on_every(0, |_, _| ());
+}
+
+#[test]
+fn until_cancelled() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    let cancel = AtomicBool::new(true);
+    let mut it = "abc".chars().index_start::<u8>(1).until_cancelled(&cancel);
+    assert_eq!(it.size_hint(), (0, Some(3)));
+    assert_eq!(it.next(), None);
+    assert!(it.is_cancelled());
+    assert_eq!(it.last_index(), None);
+    cancel.store(false, Ordering::Relaxed);
+    assert_eq!(it.next(), Some((1, 'a')));
+    assert_eq!(it.next(), Some((2, 'b')));
+    cancel.store(true, Ordering::Relaxed);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.last_index(), Some(&2));
+    cancel.store(false, Ordering::Relaxed);
+    assert_eq!(it.next(), Some((3, 'c')));
+    assert_eq!(it.next(), None);
+    assert!(!it.is_cancelled());
+}
+
+#[test]
+fn within_budget() {
+    use std::time::Duration;
+    let mut it = "abc".chars().index_start::<u8>(1).within_budget(Duration::ZERO);
+    assert_eq!(it.size_hint(), (0, Some(3)));
+    assert_eq!(it.next(), None);
+    assert!(it.is_exhausted());
+    assert_eq!(it.last_index(), None);
+    it.renew(Duration::from_secs(3600));
+    assert_eq!(it.next(), Some((1, 'a')));
+    assert_eq!(it.next(), Some((2, 'b')));
+    assert_eq!(it.last_index(), Some(&2));
+    it.renew(Duration::ZERO);
+    assert_eq!(it.next(), None);
+    it.renew(Duration::from_secs(3600));
+    assert_eq!(it.next(), Some((3, 'c')));
+    assert_eq!(it.next(), None);
+    assert!(!it.is_exhausted());
+}
+
+#[test]
+fn paced() {
+    use std::time::{Duration, Instant};
+    let start = Instant::now();
+    let mut it = (0..4).index_step::<u8>(10, 10).paced(Duration::from_millis(20));
+    assert_eq!(it.len(), 4);
+    assert_eq!(it.next(), Some((10, 0)));
+    assert_eq!(it.next(), Some((20, 1)));
+    assert!(start.elapsed() >= Duration::from_millis(20));
+    assert_eq!(it.by_ref().last(), Some((40, 3)));
+    assert!(start.elapsed() >= Duration::from_millis(60));
+}
+
+#[test]
+fn rev_with_len() {
+    let items = "abcdef".chars().filter(|c| *c != 'c');
+    let mut it = items.index_step::<i16>(100, 10).rev_with_len(5);
+    assert_eq!(it.len(), 5);
+    assert_eq!(it.next(), Some((140, 'f')));
+    assert_eq!(it.next_back(), Some((100, 'a')));
+    assert_eq!(it.next(), Some((130, 'e')));
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.collect::<Vec<_>>(), vec![(120, 'd'), (110, 'b')]);
+}
+
+#[should_panic(expected = "the source iterator has more items than the given length")]
+#[test]
+fn rev_with_len_too_short() {
+    let _ = (0..3).filter(|_| true).index::<u8>().rev_with_len(2).count();
+}
+
+#[test]
+fn aligned_to() {
+    let result = (0..3).index_step::<u64>(17, 3).aligned_to(4).collect::<Vec<_>>();
+    assert_eq!(result, vec![(20, 0), (24, 1), (28, 2)]);
+    let result = (0..3).index_step::<u32>(16, 8).aligned_to(8).collect::<Vec<_>>();
+    assert_eq!(result, vec![(16, 0), (24, 1), (32, 2)]);
+}
+
+#[should_panic(expected = "alignment must be greater than 0")]
+#[test]
+fn aligned_to_zero() {
+    let _ = (0..3).index::<u8>().aligned_to(0);
+}
+
+#[test]
+fn index_blocks() {
+    use std::io::ErrorKind;
+    use crate::{IndexerRead, ShortBlock};
+    let data: &[u8] = b"0123456";
+    let result = data.index_blocks::<3>(ShortBlock::Discard).map(|(i, b)| (i, b.unwrap())).collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, *b"012"), (3, *b"345")]);
+    let mut it = data.index_blocks::<3>(ShortBlock::Error).skip(2);
+    let (offset, last) = it.next().unwrap();
+    assert_eq!(offset, 6);
+    assert_eq!(last.unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    assert!(it.next().is_none());
+    let data: &[u8] = b"012345";
+    assert_eq!(data.index_blocks::<3>(ShortBlock::Pad(0)).count(), 2);
+}
+
+#[should_panic(expected = "N must be greater than 0")]
+#[test]
+fn index_blocks_zero() {
+    use crate::{IndexerRead, ShortBlock};
+    let data: &[u8] = b"0123456";
+    let _ = data.index_blocks::<0>(ShortBlock::Discard);
+}
+
+#[test]
+fn track_visited() {
+    let mut it = (0..200_u32).index_step::<u16>(10, 10).filter(|(_, x)| x % 50 != 0).track_visited();
+    assert_eq!(it.next(), Some((20, 1)));
+    assert_eq!(it.nth(120), Some((1250, 124)));
+    let visited = it.into_visited();
+    assert_eq!(visited.len(), 122);
+    assert!(visited.contains(20));
+    assert!(!visited.contains(10));
+    assert!(!visited.contains(21));
+    assert_eq!(visited.iter().take(3).collect::<Vec<_>>(), vec![20, 30, 40]);
+    assert_eq!(visited.missing(1100).filter(|i| i % 10 == 0).collect::<Vec<_>>(), vec![0, 10, 510, 1010]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn checkpoint() {
+    use std::io::ErrorKind;
+    use crate::Checkpoint;
+    let path = std::env::temp_dir().join(format!("iter_index_checkpoint_{}.txt", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let mut it = "abcdef".chars().resume_from::<i16, _>(&path, -10, 5).unwrap();
+    assert_eq!(it.next(), Some((-10, 'a')));
+    assert_eq!(it.next(), Some((-5, 'b')));
+    it.checkpoint_to(&path).unwrap();
+    assert_eq!(Checkpoint::load(&path).unwrap(), Checkpoint { start: -10_i16, step: 5, consumed: 2 });
+    assert_eq!(it.next(), Some((0, 'c')));
+    let it = vec!['a', 'b', 'c', 'd'].into_iter().resume_from::<i16, _>(&path, 0, 1).unwrap();
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.collect::<Vec<_>>(), vec![(0, 'c'), (5, 'd')]);
+    std::fs::write(&path, "x\n").unwrap();
+    let error = "abc".chars().resume_from::<i16, _>(&path, 0, 1).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn index_placeholders() {
+    use crate::PlaceholderStyle;
+    let styles = [PlaceholderStyle::Dollar, PlaceholderStyle::Question, PlaceholderStyle::NumberedQuestion, PlaceholderStyle::Colon];
+    let result = styles.iter()
+        .map(|&style| (10..13).index_placeholders(style).map(|(p, _)| p.to_string()).collect::<Vec<_>>().join(","))
+        .collect::<Vec<_>>();
+    assert_eq!(result, vec!["$1,$2,$3", "?,?,?", "?1,?2,?3", ":1,:2,:3"]);
+    let (p, v) = (10..13).index_placeholders(PlaceholderStyle::Question).last().unwrap();
+    assert_eq!((p.position(), p.style(), v), (3, PlaceholderStyle::Question, 12));
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn to_table() {
+    let table = "αβ".chars().index_start::<i16>(-1).to_table(["index", "x"]);
+    assert_eq!(table, "index | x\n----- | -\n   -1 | α\n    0 | β\n");
+    let table = std::iter::empty::<(u8, u8)>().to_table(["i", "value"]);
+    assert_eq!(table, "i | value\n- | -----\n");
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn in_place_collect() {
+    let items = vec![[10_u16, 11], [20, 21], [30, 31]];
+    let ptr = items.as_ptr() as usize;
+    let result = items.into_iter().index::<u16>().map(|(i, [x, _])| (i, x)).collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, 10), (1, 20), (2, 30)]);
+    assert_eq!(result.as_ptr() as usize, ptr);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn trusted_len() {
+    fn assert_trusted_len<I: std::iter::TrustedLen>(_: &I) {}
+    let it = (0..1000_u32).index_step::<u64>(5, 5);
+    assert_trusted_len(&it);
+    let result = it.collect::<Vec<_>>();
+    assert_eq!(result.len(), 1000);
+    assert_eq!(result[999], (5000, 999));
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn try_fold() {
+    let mut it = (10..20).index_step::<u32>(100, 10);
+    assert_eq!(it.find(|(_, x)| x % 4 == 0), Some((120, 12)));
+    assert_eq!(it.next(), Some((130, 13)));
+    assert_eq!(it.position(|(i, _)| i == 150), Some(1));
+    assert_eq!(it.try_rfold(0, |acc, (i, _)| if i > 180 { Some(acc + i) } else { None }), None);
+    assert_eq!(it.next_back(), Some((170, 17)));
+    let rest = it.try_rfold(Vec::new(), |mut acc, (i, _)| { acc.push(i); Some(acc) });
+    assert_eq!(rest, Some(vec![160]));
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn advance_by() {
+    let mut it = (0..10).index_step::<u8>(100, 5);
+    assert_eq!(it.advance_by(3), Ok(()));
+    assert_eq!(it.next(), Some((115, 3)));
+    assert_eq!(it.advance_back_by(2), Ok(()));
+    assert_eq!(it.next_back(), Some((135, 7)));
+    assert_eq!(it.advance_by(10), Err(std::num::NonZeroUsize::new(7).unwrap()));
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn indexer_display() {
+    let mut it = "abc".chars().index_step::<i16>(-10, -5);
+    assert_eq!(it.to_string(), "next index -10, step -5");
+    it.nth(2);
+    assert_eq!(it.to_string(), "next index -25, step -5, 0 remaining");
+    let mut it = [1, 2, 3].iter().index::<u8>();
+    it.next();
+    assert_eq!(format!("{it}"), "next index 1, step 1, 2 remaining");
+}
+
+#[test]
+fn index_start_with() {
+    let mut calls = 0;
+    let result = "abc".chars().index_start_with::<u64, _>(|| { calls += 1; 1000 }).collect::<Vec<_>>();
+    assert_eq!(result, vec![(1000, 'a'), (1001, 'b'), (1002, 'c')]);
+    assert_eq!(calls, 1);
+    let mut it = std::iter::empty::<char>().index_start_with::<u64, _>(|| panic!("start must not be computed"));
+    assert_eq!(it.len(), 0);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn multipeek_indexed() {
+    let mut it = "abcd".chars().index_step::<u8>(0, 2).multipeek_indexed(3);
+    assert_eq!(it.peek_nth(2), Some(&(4, 'c')));
+    assert_eq!(it.peek(), Some(&(0, 'a')));
+    assert_eq!(it.size_hint(), (4, Some(4)));
+    assert_eq!(it.next(), Some((0, 'a')));
+    assert_eq!(it.peek_nth(2), Some(&(6, 'd')));
+    assert_eq!(it.nth(2), Some((6, 'd')));
+    assert_eq!(it.peek(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[should_panic(expected = "cannot peek beyond the lookahead of 2 items")]
+#[test]
+fn multipeek_indexed_beyond() {
+    let mut it = (0..5).index::<u8>().multipeek_indexed(2);
+    it.peek_nth(2);
+}
+
+#[test]
+fn with_context() {
+    let mut it = "ab".chars().index_start::<u8>(1).with_context();
+    assert_eq!(it.size_hint(), (1, Some(2)));
+    assert_eq!(it.next(), Some((1, None, 'a', Some('b'))));
+    assert_eq!(it.size_hint(), (1, Some(1)));
+    assert_eq!(it.next(), Some((2, Some('a'), 'b', None)));
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!(it.next(), None);
+    let result = std::iter::once(7).index::<u8>().with_context().collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, None, 7, None)]);
+}
+
+#[test]
+fn with_context_non_fused_source() {
+    let mut n = 0;
+    let source = core::iter::from_fn(move || { n += 1; if n % 3 == 0 { None } else { Some(n) } });
+    let mut it = source.index::<u8>().with_context();
+    assert_eq!(it.next(), Some((0, None, 1, Some(2))));
+    assert_eq!(it.next(), Some((1, Some(1), 2, None)));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    let mut n = 0;
+    let mut it = core::iter::from_fn(move || { n += 1; if n == 1 { None } else { Some(n) } }).index::<u8>().with_context();
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn check_sorted() {
+    use crate::UnsortedError;
+    let result = "abbd".chars().index_step::<u8>(10, 10).check_sorted_by(|a, b| a.cmp(b)).collect::<Result<Vec<_>, _>>();
+    assert_eq!(result, Ok(vec![(10, 'a'), (20, 'b'), (30, 'b'), (40, 'd')]));
+    let mut it = "abad".chars().index_step::<u8>(10, 10).check_sorted_by(|a, b| a.cmp(b));
+    assert_eq!(it.nth(2), Some(Err(UnsortedError { index: 30 })));
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!(it.next(), None);
+    let mut it = ["x", "yy", "z"].iter().index::<u8>().check_sorted_by_key(|s| s.len());
+    assert_eq!(it.next(), Some(Ok((0, &"x"))));
+    assert_eq!(it.next(), Some(Ok((1, &"yy"))));
+    let error = it.next().unwrap().unwrap_err();
+    assert_eq!(error.to_string(), "item at index 2 is out of order");
+}
+
+#[test]
+fn check_sorted_non_fused_source() {
+    let mut n = 0;
+    let source = core::iter::from_fn(move || { n += 1; if n == 3 { None } else { Some(n) } });
+    let mut it = source.index::<u8>().check_sorted_by(|a, b| a.cmp(b));
+    assert_eq!(it.nth(1), Some(Ok((1, 2))));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    let mut n = 0;
+    let source = core::iter::from_fn(move || { n += 1; if n == 2 { None } else { Some(n) } });
+    let mut it = source.index::<u8>().check_sorted_by_key(|&x| x);
+    assert_eq!(it.next(), Some(Ok((0, 1))));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn path_indexed() {
+    use crate::Nested;
+    #[derive(Clone, Debug, PartialEq)]
+    enum Node { Leaf(u8), List(Vec<Node>) }
+    impl Nested for Node {
+        type Children = std::vec::IntoIter<Node>;
+        fn into_children(self) -> Result<Self::Children, Self> {
+            match self {
+                Node::List(nodes) => Ok(nodes.into_iter()),
+                leaf => Err(leaf),
+            }
+        }
+    }
+    use Node::*;
+    let data = vec![
+        List(vec![]),
+        List(vec![Leaf(1), List(vec![List(vec![]), List(vec![Leaf(2), Leaf(3)])]), Leaf(4)]),
+        Leaf(5),
+    ];
+    let result = data.into_iter().path_indexed::<u16>().map(|(p, n)| (p.into_vec(), n)).collect::<Vec<_>>();
+    assert_eq!(result, vec![
+        (vec![1, 0], Leaf(1)),
+        (vec![1, 1, 1, 0], Leaf(2)),
+        (vec![1, 1, 1, 1], Leaf(3)),
+        (vec![1, 2], Leaf(4)),
+        (vec![2], Leaf(5)),
+    ]);
+    let mut it = vec![List(vec![Leaf(6), Leaf(7)])].into_iter().path_indexed::<u16>();
+    assert_eq!(it.next().map(|(p, n)| (p.into_vec(), n)), Some((vec![0, 0], Leaf(6))));
+    let copy = it.clone();
+    assert!(format!("{copy:?}").starts_with("PathIndexer"));
+    assert_eq!(copy.map(|(p, n)| (p.into_vec(), n)).collect::<Vec<_>>(), vec![(vec![0, 1], Leaf(7))]);
+    assert_eq!(it.count(), 1);
+}
+
+#[test]
+fn index_preorder() {
+    let nodes = vec!["fn", " args", "  x", " body"];
+    let it = nodes.into_iter().index_preorder::<u16, _>(|s| s.len() - s.trim_start().len());
+    assert_eq!(it.len(), 4);
+    let result = it.map(|(n, _)| n).collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, 0), (1, 1), (2, 2), (3, 1)]);
+}
+
+#[test]
+fn index_non_debug_conversion_error() {
+    use std::ops::{Add, AddAssign, Mul};
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    struct Id(u32);
+    struct NoDebug;
+    impl From<u8> for Id {
+        fn from(v: u8) -> Self { Id(v.into()) }
+    }
+    impl TryFrom<usize> for Id {
+        type Error = NoDebug;
+        fn try_from(v: usize) -> Result<Self, NoDebug> { u32::try_from(v).map(Id).map_err(|_| NoDebug) }
+    }
+    impl AddAssign<&Id> for Id {
+        fn add_assign(&mut self, rhs: &Id) { self.0 += rhs.0 }
+    }
+    impl Add for &Id {
+        type Output = Id;
+        fn add(self, rhs: &Id) -> Id { Id(self.0 + rhs.0) }
+    }
+    impl Mul for &Id {
+        type Output = Id;
+        fn mul(self, rhs: &Id) -> Id { Id(self.0 * rhs.0) }
+    }
+    let mut it = "abcd".chars().index_start(Id(5));
+    assert_eq!(it.next(), Some((Id(5), 'a')));
+    assert_eq!(it.nth(1), Some((Id(7), 'c')));
+}
+
+#[test]
+fn count_with_last_index() {
+    let mut it = "abcdef".chars().index_step::<i16>(100, -10);
+    it.next();
+    assert_eq!(it.count_with_last_index(), (5, Some(50)));
+    let mut it = "a".chars().index::<u8>();
+    it.next();
+    assert_eq!(it.count_with_last_index(), (0, None));
+}
+
+#[test]
+fn map_indexed_chunks() {
+    let mut it = "abcdefg".chars().index_start::<u8>(1).map_indexed_chunks::<3, _, _, _, _>(|i, c| {
+        (i.iter().map(|&i| u32::from(i)).sum::<u32>(), c.iter().collect::<String>())
+    });
+    assert_eq!(it.size_hint(), (0, Some(2)));
+    assert_eq!(it.next(), Some((6, "abc".to_string())));
+    assert_eq!(it.next(), Some((15, "def".to_string())));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.remainder(), (&[7][..], &['g'][..]));
+}
+
+#[test]
+fn index_entries() {
+    use std::collections::BTreeMap;
+    let map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+    let mut it = map.into_iter().index_entries::<u8>();
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.next_back(), Some((2, 3, 'c')));
+    assert_eq!(it.next(), Some((0, 1, 'a')));
+    assert_eq!(it.next(), Some((1, 2, 'b')));
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn into_enumerate() {
+    let mut it = "abc".chars().index::<usize>();
+    it.next();
+    let mut it = it.into_enumerate().unwrap_err();
+    assert_eq!(it.next(), Some((1, 'b')));
+    let it = "abc".chars().index_step::<usize>(0, 2).into_enumerate();
+    assert!(it.is_err());
+    let it = "abc".chars().index::<usize>().into_enumerate();
+    assert_eq!(it.unwrap().last(), Some((2, 'c')));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn for_each_indexed_parallel() {
+    use std::sync::Mutex;
+    let seen = Mutex::new(Vec::new());
+    let items = (0..100).map(|x| x * 2).collect::<Vec<_>>();
+    items.iter().index_step::<i32>(-100, 3).for_each_indexed_parallel(3, 7, |i, &x| {
+        seen.lock().unwrap().push((i, x));
+    });
+    let mut seen = seen.into_inner().unwrap();
+    seen.sort();
+    assert_eq!(seen, (0..100).map(|x| (-100 + 3 * x, x * 2)).collect::<Vec<_>>());
+    let seen = Mutex::new(Vec::new());
+    let mut items = (0_u32..10).index_step::<u8>(10, 5);
+    items.nth(1);
+    items.for_each_indexed_parallel(8, 3, |i, x| seen.lock().unwrap().push((i, x)));
+    let mut seen = seen.into_inner().unwrap();
+    seen.sort();
+    assert_eq!(seen, (2..10).map(|x| (10 + 5 * x as u8, x)).collect::<Vec<_>>());
+    (0..0).index::<u8>().for_each_indexed_parallel(2, 1, |_, _| panic!());
+}
+
+#[should_panic(expected = "threads must be greater than 0")]
+#[test]
+fn for_each_indexed_parallel_no_thread() {
+    (0..3).index::<u8>().for_each_indexed_parallel(0, 1, |_, _| ());
+}
+
+#[cfg(feature = "crossbeam")]
+#[test]
+fn fan_out() {
+    let (fan_out, receivers) = "abcde".chars().index_start::<u8>(1).fan_out(2, 5);
+    fan_out.run().unwrap();
+    assert_eq!(receivers[0].iter().collect::<Vec<_>>(), vec![(1, 'a'), (3, 'c'), (5, 'e')]);
+    assert_eq!(receivers[1].iter().collect::<Vec<_>>(), vec![(2, 'b'), (4, 'd')]);
+    let (fan_out, mut receivers) = "abc".chars().index::<u8>().fan_out_by(2, 5, |&i, _| usize::from(i == 1));
+    receivers.remove(1);
+    assert_eq!(fan_out.run().unwrap_err().into_inner(), (1, 'b'));
+    assert_eq!(receivers[0].iter().collect::<Vec<_>>(), vec![(0, 'a')]);
+}
+
+#[test]
+fn reorder_by_index() {
+    use crate::ReorderError;
+    let mut it = vec![(2_u8, 'c'), (0, 'a'), (1, 'b'), (4, 'e'), (3, 'd')].into_iter().reorder_by_index(1);
+    assert_eq!(it.next(), Some(Ok((0, 'a'))));
+    assert_eq!(it.next(), Some(Ok((1, 'b'))));
+    assert_eq!(it.next(), Some(Ok((2, 'c'))));
+    assert_eq!(it.size_hint(), (0, Some(2)));
+    assert_eq!(it.collect::<Vec<_>>(), vec![Ok((3, 'd')), Ok((4, 'e'))]);
+    let mut it = vec![(1_u8, 'b'), (2, 'c'), (0, 'a')].into_iter().reorder_by_index(1);
+    assert_eq!(it.next(), Some(Err(ReorderError::Gap(0))));
+    assert_eq!(it.next(), None);
+    let result = vec![(0_u8, 'a'), (2, 'c')].into_iter().reorder_by_index(5).collect::<Vec<_>>();
+    assert_eq!(result, vec![Ok((0, 'a')), Err(ReorderError::Gap(1))]);
+    let result = vec![(0_u8, 'a'), (0, 'b')].into_iter().reorder_by_index(5).collect::<Vec<_>>();
+    assert_eq!(result, vec![Ok((0, 'a')), Err(ReorderError::Duplicate(0))]);
+    assert_eq!(ReorderError::Gap(1).to_string(), "missing item at index 1");
+}
+
+#[test]
+fn reorder_by_index_step() {
+    use crate::ReorderError;
+    let mut shuffled = "abcde".chars().index_start::<u8>(1).collect::<Vec<_>>();
+    shuffled.reverse();
+    let result = shuffled.into_iter().reorder_by_index_step(1, 1, 5).collect::<Result<Vec<_>, _>>();
+    assert_eq!(result, Ok("abcde".chars().index_start::<u8>(1).collect::<Vec<_>>()));
+    let result = vec![(7_i32, 'b'), (5, 'a'), (11, 'd')].into_iter().reorder_by_index_step(5, 2, 5).collect::<Vec<_>>();
+    assert_eq!(result, vec![Ok((5, 'a')), Ok((7, 'b')), Err(ReorderError::Gap(9))]);
+    let result = vec![(5_i32, 'a'), (3, 'z')].into_iter().reorder_by_index_step(5, 2, 5).collect::<Vec<_>>();
+    assert_eq!(result, vec![Ok((5, 'a')), Err(ReorderError::Duplicate(3))]);
+}
+
+#[test]
+fn merge_indexed() {
+    use crate::merge_indexed;
+    let a = "ace".chars().index_step::<u8>(0, 2);
+    let b = "bd".chars().index_step::<u8>(1, 2);
+    let c = "x".chars().index_start::<u8>(2);
+    let empty = "".chars().index::<u8>();
+    let it = merge_indexed(vec![a, b, empty, c]);
+    assert_eq!(it.size_hint(), (3, Some(6)));
+    let result = it.collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, 'a'), (1, 'b'), (2, 'c'), (2, 'x'), (3, 'd'), (4, 'e')]);
+    let mut it = merge_indexed([vec![(1, 'a')], vec![(0, 'b')]]);
+    assert_eq!(it.len(), 2);
+    it.next();
+    assert_eq!(it.len(), 1);
+}
+
+#[test]
+fn index_group_number() {
+    let result = "aabaccc".chars().index_group_number::<i16, _, _>(|&c| c).collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, 'a'), (0, 'a'), (1, 'b'), (2, 'a'), (3, 'c'), (3, 'c'), (3, 'c')]);
+    let it = [1, 3, 2, 4].iter().index_group_number::<u8, _, _>(|&&x| x % 2);
+    assert_eq!(it.len(), 4);
+    assert_eq!(it.map(|(g, _)| g).collect::<Vec<_>>(), vec![0, 0, 1, 1]);
+}
+
+#[test]
+fn index_elapsed() {
+    use std::time::Duration;
+    let delays = [5, 0, 10];
+    let result = delays.iter()
+        .inspect(|&&ms| std::thread::sleep(Duration::from_millis(ms)))
+        .index_elapsed::<u8>()
+        .map(|((i, elapsed), &ms)| (i, elapsed >= Duration::from_millis(ms)))
+        .collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, true), (1, true), (2, true)]);
+}
+
+#[test]
+fn index_bits() {
+    use crate::BitOrder;
+    let mut it = [0b1010_0000_u8, 0xff].iter().copied().index_bits::<u16>(BitOrder::LsbFirst);
+    assert_eq!(it.size_hint(), (16, Some(16)));
+    let result = it.by_ref().take(8).map(|(_, b)| u8::from(b)).collect::<Vec<_>>();
+    assert_eq!(result, vec![0, 0, 0, 0, 0, 1, 0, 1]);
+    assert_eq!(it.size_hint(), (8, Some(8)));
+    assert_eq!(it.next(), Some((8, true)));
+    assert_eq!(it.size_hint(), (7, Some(7)));
+    let result = [0b1010_0000_u8].iter().copied().index_bits::<u16>(BitOrder::MsbFirst).take(3).collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, true), (1, false), (2, true)]);
+}
+
+#[test]
+fn serial_number() {
+    use crate::SerialNumber;
+    assert!(250_u8.seq_lt(3));
+    assert!(3_u8.seq_gt(250));
+    assert!(!3_u8.seq_lt(3));
+    assert_eq!(250_u8.seq_distance(3), 9);
+    assert_eq!(3_u8.seq_distance(250), -9);
+    assert!(!0_u8.seq_lt(128) && !0_u8.seq_gt(128));
+    assert!(0_u8.seq_lt(127));
+    assert!(u32::MAX.seq_lt(0));
+
+    use std::num::Wrapping;
+    let mut iter = (0..300).index_wrapping::<u8>();
+    let (a, _) = iter.nth(250).unwrap();
+    let (b, _) = iter.nth(10).unwrap();
+    assert_eq!(b, Wrapping(5));
+    assert!(a.seq_lt(b) && b.seq_gt(a));
+    assert_eq!(a.seq_distance(b), 11_i8);
+    assert!(!Wrapping(0_u16).seq_lt(Wrapping(32768)));
+}
+
+#[test]
+fn index_tiled() {
+    // 5 x 3 buffer, 2 x 2 tiles: the last column and the last row are partial tiles
+    let result = (0..15).index_tiled(5, 2, 2).map(|(t, _)| t).collect::<Vec<_>>();
+    assert_eq!(result, vec![
+        (0, 0, 0), (0, 0, 1), (0, 1, 0), (0, 1, 1), (0, 2, 0),
+        (0, 0, 2), (0, 0, 3), (0, 1, 2), (0, 1, 3), (0, 2, 2),
+        (1, 0, 0), (1, 0, 1), (1, 1, 0), (1, 1, 1), (1, 2, 0),
+    ]);
+    assert_eq!((0..15).index_tiled(5, 2, 2).len(), 15);
+}
+
+#[test]
+fn index_array() {
+    use crate::IndexedArray;
+    let result: [(u64, char); 3] = ['a', 'b', 'c'].index_array();
+    assert_eq!(result, [(0, 'a'), (1, 'b'), (2, 'c')]);
+    let result = [(); 0].index_array::<u8>();
+    assert_eq!(result, []);
+    let result = [1.5, 2.5].index_array_step::<u32>(100, 5);
+    assert_eq!(result, [(100, 1.5), (105, 2.5)]);
+}
+
+#[test]
+fn index_fused() {
+    fn assert_fused<I: std::iter::FusedIterator>(_: &I) {}
+    let mut result = vec![1, 2].into_iter().index::<u8>();
+    assert_fused(&result);
+    assert_eq!(result.by_ref().count(), 2);
+    assert_eq!(result.next(), None);
+}
+
+#[test]
+fn index_fold() {
+    let items = [1, 2, 3, 4];
+    let sum = items.iter().index_step::<u32>(10, 10).fold(0, |acc, (i, &x)| acc + i * x);
+    assert_eq!(sum, 10 + 40 + 90 + 160);
+    let mut it = items.iter().index::<u8>();
+    it.next();
+    let mut result = Vec::new();
+    it.for_each(|(i, &x)| result.push((i, x)));
+    assert_eq!(result, vec![(1, 2), (2, 3), (3, 4)]);
+}
+
+#[test]
+fn index_last() {
+    assert_eq!([1, 2, 3].iter().index_step::<i16>(-10, 5).last(), Some((0, &3)));
+    assert_eq!((0..10).filter(|x| x % 3 == 0).index_start::<u8>(1).last(), Some((4, 9)));
+    assert_eq!(std::iter::empty::<char>().index::<u8>().last(), None);
+
+    // a source whose exact size hint is wrong
+    struct Liar(u8);
+    impl Iterator for Liar {
+        type Item = u8;
+        fn next(&mut self) -> Option<u8> {
+            self.0 = self.0.checked_sub(1)?;
+            Some(self.0)
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (5, Some(5))
+        }
+    }
+    let expected = if cfg!(feature = "nightly") { 1 } else { 4 };
+    assert_eq!(Liar(2).index::<u8>().last(), Some((expected, 0)));
+}
+
+#[test]
+fn index_try_nth() {
+    use crate::IndexError;
+    let mut it = (0..10).index_step::<i16>(-100, 50);
+    assert_eq!(it.try_nth(1), Ok(Some((-50, 1))));
+    assert_eq!(it.try_nth(70000), Err(IndexError::ConversionFailed));
+    assert_eq!(it.next(), Some((0, 2)));
+    assert_eq!(it.try_nth(10), Ok(None));
+    let mut it = (0..300).index::<u8>();
+    assert_eq!(it.try_nth(254), Ok(Some((254, 254))));
+    assert_eq!(it.try_nth(0), Err(IndexError::Overflow));
+    assert_eq!(IndexError::Overflow.to_string(), "the index overflowed the index type");
+}
+
+#[test]
+fn index_default_type() {
+    fn first_even(items: &[u32]) -> Option<<crate::Indexer<std::slice::Iter<'_, u32>> as Iterator>::Item> {
+        items.iter().index().find(|(_, &x)| x % 2 == 0)
+    }
+    assert_eq!(first_even(&[1, 3, 4, 5]), Some((2_usize, &4)));
+}
+
+#[test]
+fn index_checked() {
+    let mut it = (0..1000).index_checked::<u8>();
+    assert_eq!(it.next(), Some((Some(0), 0)));
+    assert_eq!(it.nth(254), Some((Some(255), 255)));
+    assert_eq!(it.next(), Some((None, 256)));
+    assert_eq!(it.len(), 743);
+    assert!(it.all(|(i, _)| i.is_none()));
+}
+
+#[test]
+fn index_saturating() {
+    let mut it = (0..1000).index_saturating::<u8>(0, 1);
+    assert_eq!(it.nth(254), Some((254, 254)));
+    assert_eq!(it.next(), Some((255, 255)));
+    assert_eq!(it.next(), Some((255, 256)));
+    assert_eq!(it.last(), Some((255, 999)));
+    let result = "abc".chars().index_saturating::<i32>(i32::MAX - 1, 1).collect::<Vec<_>>();
+    assert_eq!(result, vec![(i32::MAX - 1, 'a'), (i32::MAX, 'b'), (i32::MAX, 'c')]);
+}
+
+#[test]
+fn index_overflow_policy() {
+    use crate::OverflowPolicy;
+    let indices = |policy| (0..4).index_step::<i16>(32765, 1).with_overflow_policy(policy).map(|(i, _)| i).collect::<Vec<_>>();
+    assert_eq!(indices(OverflowPolicy::Saturate), vec![32765, 32766, 32767, 32767]);
+    assert_eq!(indices(OverflowPolicy::Wrap), vec![32765, 32766, 32767, -32768]);
+    assert_eq!(indices(OverflowPolicy::StopIteration), vec![32765, 32766, 32767]);
+    let mut it = (0..4).index_start::<u8>(254).with_overflow_policy(OverflowPolicy::StopIteration);
+    assert_eq!(it.size_hint(), (2, Some(2)));
+    assert_eq!(it.len(), 2);
+    it.next();
+    assert_eq!(it.len(), 1);
+    it.by_ref().count();
+    assert_eq!(it.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn index_overflow_policy_stop_bounds() {
+    use crate::OverflowPolicy::{StopIteration, Wrap};
+    let it = (0..10).index_step::<i8>(-100, -10).with_overflow_policy(StopIteration);
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.collect::<Vec<_>>().len(), 3);
+    let it = (0..10).index_step::<i8>(i8::MAX, 0).with_overflow_policy(StopIteration);
+    assert_eq!(it.len(), 10);
+    let it = (0..).index_step::<u8>(0, 100).with_overflow_policy(StopIteration);
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    assert_eq!(it.count(), 3);
+    let it = (0..).index::<u64>().with_overflow_policy(StopIteration);
+    assert_eq!(it.size_hint(), (usize::MAX, None));
+    let it = (0..).filter(|x| x % 2 == 0).index_start::<u8>(250).with_overflow_policy(StopIteration);
+    assert_eq!(it.size_hint(), (0, Some(6)));
+    let it = (0..10).index_start::<u8>(250).with_overflow_policy(Wrap);
+    assert_eq!(it.len(), 10);
+}
+
+#[should_panic(expected = "the index overflowed u8")]
+#[test]
+fn index_overflow_policy_panic() {
+    use crate::OverflowPolicy;
+    let mut it = (0..=256).index::<u8>().with_overflow_policy(OverflowPolicy::Panic);
+    assert_eq!(it.nth(255), Some((255, 255)));
+    it.next();
+}
+
+#[test]
+fn index_desc() {
+    let result = "abc".chars().index_desc::<usize>(10).collect::<Vec<_>>();
+    assert_eq!(result, vec![(10, 'a'), (9, 'b'), (8, 'c')]);
+    let it = (0..=255_u8).index_desc::<u8>(255);
+    assert_eq!(it.len(), 256);
+    assert_eq!(it.last(), Some((0, 255)));
+    let result = [1.5, 2.5].iter().index_desc_step::<i32>(0, 5).map(|(i, _)| i).collect::<Vec<_>>();
+    assert_eq!(result, vec![0, -5]);
+}
+
+#[test]
+fn index_from_end() {
+    let mut it = "abcdef".chars().collect::<Vec<_>>().into_iter().index_from_end::<u8>();
+    assert_eq!(it.next(), Some((5, 'a')));
+    assert_eq!(it.next_back(), Some((0, 'f')));
+    assert_eq!(it.nth(1), Some((3, 'c')));
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.rev().collect::<Vec<_>>(), vec![(1, 'e'), (2, 'd')]);
+}
+
+#[test]
+fn index_step_signed() {
+    let result = "abc".chars().index_step_signed::<u8, i8>(200, 20).map(|(i, _)| i).collect::<Vec<_>>();
+    assert_eq!(result, vec![200, 220, 240]);
+    let result = "abc".chars().index_step_signed::<usize, isize>(256, -128).map(|(i, _)| i).collect::<Vec<_>>();
+    assert_eq!(result, vec![256, 128, 0]);
+    let result = (0..3).index_step_signed::<u8, i8>(255, -127).map(|(i, _)| i).collect::<Vec<_>>();
+    assert_eq!(result, vec![255, 128, 1]);
+}
+
+#[test]
+fn index_scale() {
+    let result = (0..8).index_scale::<u8>(1, 2).map(|(i, _)| i).collect::<Vec<_>>();
+    assert_eq!(result, vec![1, 2, 4, 8, 16, 32, 64, 128]);
+    let result = "abc".chars().index_scale::<f64>(1.0, 0.5).collect::<Vec<_>>();
+    assert_eq!(result, vec![(1.0, 'a'), (0.5, 'b'), (0.25, 'c')]);
+    assert_eq!((0..5).index_scale::<i32>(1, -10).len(), 5);
+}
+
+#[test]
+fn index_with() {
+    let mut n = 0;
+    let result = "abc".chars().index_with(|| { n += 3; n }).collect::<Vec<_>>();
+    assert_eq!(result, vec![(3, 'a'), (6, 'b'), (9, 'c')]);
+    let mut it = vec!['a', 'b', 'c', 'd'].into_iter().index_successors(String::from("x"), |s| format!("{s}'"));
+    assert_eq!(it.len(), 4);
+    assert_eq!(it.nth(2), Some(("x''".to_string(), 'c')));
+}
+
+#[test]
+fn index_by() {
+    use crate::Exhaustion;
+    let mut it = (0..5).index_by(["a", "b"], Exhaustion::Stop);
+    assert_eq!(it.size_hint(), (2, Some(2)));
+    assert_eq!(it.by_ref().collect::<Vec<_>>(), vec![("a", 0), ("b", 1)]);
+    let it = (0..3).index_by(std::iter::empty::<u8>(), Exhaustion::RepeatLast);
+    assert_eq!(it.count(), 0);
+    let mut it = (0..).index_by(["x", "y", "z"], Exhaustion::Stop);
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    it.next();
+    assert_eq!(it.size_hint(), (2, Some(2)));
+    let it = (0..3).index_by(10.., Exhaustion::Stop);
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    let it = (0..10).filter(|x| x % 2 == 0).index_by(0..4, Exhaustion::Stop);
+    assert_eq!(it.size_hint(), (0, Some(4)));
+    assert_eq!(it.count(), 4);
+    let it = (0..3).index_by((10..).step_by(10), Exhaustion::Panic);
+    assert_eq!(it.collect::<Vec<_>>(), vec![(10, 0), (20, 1), (30, 2)]);
+}
+
+#[should_panic(expected = "the index iterator is exhausted")]
+#[test]
+fn index_by_panic() {
+    let it = (0..3).index_by([1, 2], crate::Exhaustion::Panic);
+    it.for_each(drop);
+}
+
+#[test]
+fn index_alpha() {
+    use crate::{Alpha, LetterCase};
+    let labels = (0..703).index_alpha("a").map(|(l, _)| l).collect::<Vec<_>>();
+    assert_eq!(labels[701].to_string(), "zz");
+    assert_eq!(labels[702].to_string(), "aaa");
+    assert_eq!(labels[702].position(), 703);
+    let start = Alpha::parse("AZ").unwrap();
+    assert_eq!((start.position(), start.case()), (52, LetterCase::Upper));
+    let result = "ab".chars().index_alpha("AZ").map(|(l, c)| format!("{l}{c}")).collect::<Vec<_>>();
+    assert_eq!(result, vec!["AZa", "BAb"]);
+    assert_eq!(Alpha::parse(""), None);
+    assert_eq!(Alpha::parse("aB"), None);
+    assert_eq!(Alpha::parse("a1"), None);
+    assert_eq!(Alpha::parse("zzzzzzzzzzzzzzzzzz"), None);
+}
+
+#[test]
+fn index_roman() {
+    use crate::LetterCase;
+    let numerals = (0..4000).index_roman(LetterCase::Lower).map(|(n, _)| n).collect::<Vec<_>>();
+    let text = |i: usize| numerals[i - 1].to_string();
+    assert_eq!(text(9), "ix");
+    assert_eq!(text(14), "xiv");
+    assert_eq!(text(49), "xlix");
+    assert_eq!(text(1994), "mcmxciv");
+    assert_eq!(text(3999), "mmmcmxcix");
+    assert_eq!(text(4000), "mmmm");
+    assert_eq!(numerals[41].value(), 42);
+}
+
+#[test]
+fn index_ordinal() {
+    let result = (0..15).index_ordinal(100).map(|(o, _)| o.to_string()).collect::<Vec<_>>();
+    assert_eq!(result[..4], ["100th", "101st", "102nd", "103rd"]);
+    assert_eq!(result[11..], ["111th", "112th", "113th", "114th"]);
+    let result = (0..3).index_ordinal(21).map(|(o, _)| (o.value(), o.suffix())).collect::<Vec<_>>();
+    assert_eq!(result, vec![(21, "st"), (22, "nd"), (23, "rd")]);
+    assert_eq!((0..1).index_ordinal(0).next().unwrap().0.to_string(), "0th");
+}
+
+#[test]
+fn index_format() {
+    let mut it = ['a', 'b', 'c', 'd'].into_iter().index_format(-3_i16, 2, |i| format!("<{i:+}>"));
+    assert_eq!(it.len(), 4);
+    assert_eq!(it.next(), Some(("<-3>".to_string(), 'a')));
+    assert_eq!(it.nth(2), Some(("<+3>".to_string(), 'd')));
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn index_radix() {
+    let labels = (0..200).index_radix::<u8>(16, 2).map(|(l, _)| l).collect::<Vec<_>>();
+    assert_eq!(labels[163].to_string(), "0xA3");
+    assert_eq!(labels[5].to_string(), "0x05");
+    let labels = (0..6).index_radix::<u64>(2, 0).map(|(l, _)| l.to_string()).collect::<Vec<_>>();
+    assert_eq!(labels, vec!["0b0", "0b1", "0b10", "0b11", "0b100", "0b101"]);
+    let label = (0..36).index_radix::<u16>(36, 3).last().unwrap().0;
+    assert_eq!((label.to_string(), label.value(), label.radix()), ("00Z".to_string(), 35, 36));
+    let labels = (0..20).index_radix::<usize>(16, 8).map(|(l, _)| l.to_string()).collect::<Vec<_>>();
+    assert_eq!((labels[0].as_str(), labels[19].as_str()), ("0x00000000", "0x00000013"));
+    let labels = (0..3).index_radix::<i32>(8, 2).map(|(l, _)| l.to_string()).collect::<Vec<_>>();
+    assert_eq!(labels, vec!["0o00", "0o01", "0o02"]);
+}
+
+#[test]
+fn index_padded() {
+    let labels = (0..10).index_padded(0).map(|(l, _)| l.to_string()).collect::<Vec<_>>();
+    assert_eq!(labels[..2], ["0", "1"]);
+    assert_eq!(labels[9], "9");
+    let labels = (0..11).index_padded(0).map(|(l, _)| l).collect::<Vec<_>>();
+    assert_eq!((labels[3].to_string(), labels[3].value(), labels[3].width()), ("03".to_string(), 3, 2));
+    assert_eq!(std::iter::empty::<()>().index_padded(99).next(), None);
+}
+
+#[test]
+fn index_2d() {
+    let result = (0..7).index_2d::<u8>(3).collect::<Vec<_>>();
+    assert_eq!(result, vec![((0, 0), 0), ((0, 1), 1), ((0, 2), 2), ((1, 0), 3), ((1, 1), 4), ((1, 2), 5), ((2, 0), 6)]);
+    let result = (0..3).index_2d::<i32>(1).map(|(p, _)| p).collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, 0), (1, 0), (2, 0)]);
+}
+
+#[test]
+fn index_3d() {
+    let result = (0..13).index_3d::<u8>(3, 2).map(|(p, _)| p).collect::<Vec<_>>();
+    assert_eq!(result[..4], [(0, 0, 0), (1, 0, 0), (2, 0, 0), (0, 1, 0)]);
+    assert_eq!(result[5..7], [(2, 1, 0), (0, 0, 1)]);
+    assert_eq!(result[12], (0, 0, 2));
+}
+
+#[test]
+fn index_nd() {
+    let result = (0..7).index_nd::<u8, 2>([2, 3]).map(|(c, _)| c).collect::<Vec<_>>();
+    assert_eq!(result, vec![[0, 0], [0, 1], [0, 2], [1, 0], [1, 1], [1, 2], [2, 0]]);
+    let result = (0..5).index_nd::<usize, 1>([2]).map(|(c, _)| c[0]).collect::<Vec<_>>();
+    assert_eq!(result, vec![0, 1, 2, 3, 4]);
+    let last = (0..2 * 3 * 4 * 5).index_nd::<u16, 4>([2, 3, 4, 5]).last();
+    assert_eq!(last, Some(([1, 2, 3, 4], 119)));
+}
+
+#[test]
+fn index_chunked() {
+    let result = (0..5).index_chunked::<u64>(2).map(|(c, _)| c).collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, 0), (0, 1), (1, 0), (1, 1), (2, 0)]);
+}
+
+#[test]
+fn index_paged() {
+    let result = (0..4).index_paged::<i16>(3, -1).map(|(p, _)| p).collect::<Vec<_>>();
+    assert_eq!(result, vec![(-1, 0), (-1, 1), (-1, 2), (0, 0)]);
+    assert_eq!((0..1000).index_paged::<u8>(250, 0).last(), Some(((3, 249), 999)));
+}
+
+#[test]
+fn index_by_group() {
+    let values = vec![3, 8, 5, 2, 7, 4];
+    let mut iter = values.into_iter().index_by_group::<u8, _, _>(|v| v % 2);
+    assert_eq!(iter.len(), 6);
+    let result = iter.by_ref().collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, 3), (0, 8), (1, 5), (1, 2), (2, 7), (2, 4)]);
+    assert_eq!(iter.counters(), &HashMap::from([(0, 3), (1, 3)]));
+}
+
+#[test]
+fn index_if() {
+    let values = vec![1, -2, 3, -4, -5, 6];
+    let mut iter = values.into_iter().index_if::<u8, _>(|v| *v < 0);
+    assert_eq!(iter.len(), 6);
+    let result = iter.by_ref().take(4).collect::<Vec<_>>();
+    assert_eq!(result, vec![(None, 1), (Some(0), -2), (None, 3), (Some(1), -4)]);
+    assert_eq!(iter.next_index(), &2);
+    let result = iter.collect::<Vec<_>>();
+    assert_eq!(result, vec![(Some(2), -5), (None, 6)]);
+}
+
+#[test]
+fn index_every() {
+    let iter = (0..8).index_every::<u8>(2);
+    assert_eq!(iter.len(), 8);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![(0, 0), (0, 1), (1, 2), (1, 3), (2, 4), (2, 5), (3, 6), (3, 7)]);
+
+    // the index doesn't move past the last batch
+    let result = (0..512).index_every::<u8>(2).last();
+    assert_eq!(result, Some((255, 511)));
+}
+
+#[test]
+#[should_panic(expected = "n must be greater than 0")]
+fn index_every_zero() {
+    let _ = (0..8).index_every::<u8>(0);
+}
+
+#[test]
+fn index_mod() {
+    let iter = (10..17).index_mod::<u8>(3);
+    assert_eq!(iter.len(), 7);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![(0, 10), (1, 11), (2, 12), (0, 13), (1, 14), (2, 15), (0, 16)]);
+
+    let result = (0..1000).index_mod::<u8>(255).map(|(i, _)| i).max();
+    assert_eq!(result, Some(254));
+    let result = (0..1000).index_mod::<u8>(1).all(|(i, _)| i == 0);
+    assert!(result);
+}
+
+#[test]
+#[should_panic(expected = "the modulus must be greater than 0")]
+fn index_mod_zero() {
+    let _ = (0..8).index_mod::<u8>(0);
+}
+
+#[test]
+fn index_cycles() {
+    let result = [1, 2].into_iter().cycle().index_cycles::<u8>(2).take(5).collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, 0, 1), (0, 1, 2), (1, 0, 1), (1, 1, 2), (2, 0, 1)]);
+    let mut it = "abc".chars().index_cycles::<u16>(1);
+    assert_eq!(it.nth(2), Some((2, 0, 'c')));
+    assert_eq!(it.size_hint(), (0, Some(0)));
+}
+
+#[should_panic(expected = "the cycle length must be greater than 0")]
+#[test]
+fn index_cycles_zero() {
+    let _ = [1].into_iter().cycle().index_cycles::<u8>(0);
+}
+
+#[cfg(feature = "rational")]
+#[test]
+fn index_rational() {
+    use num_rational::Ratio;
+    use crate::CheckedIndex;
+    let third = Ratio::new(1_i64, 3);
+    let mut iter = (0..7).index_step(Ratio::from_integer(0), third);
+    assert_eq!(iter.next(), Some((Ratio::from_integer(0), 0)));
+    assert_eq!(iter.nth(2), Some((Ratio::from_integer(1), 3)));
+    assert_eq!(iter.next_back(), Some((Ratio::new(2, 1), 6)));
+    assert_eq!(iter.last(), Some((Ratio::new(5, 3), 5)));
+    assert_eq!(Ratio::new(i64::MAX, 2).checked_add(&Ratio::new(i64::MAX, 2)), None);
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn index_bigint() {
+    use num_bigint::BigUint;
+    let start = BigUint::from(u64::MAX) - 1_u8;
+    let mut iter = (0..10).index_start(start.clone());
+    assert_eq!(iter.next(), Some((start.clone(), 0)));
+    assert_eq!(iter.nth(2), Some((start.clone() + 3_u8, 3)));
+    assert_eq!(iter.try_nth(1), Ok(Some((start.clone() + 5_u8, 5))));
+    assert_eq!(iter.last(), Some((start + 9_u8, 9)));
+
+    let result = (0..3).index::<BigUint>().map(|(i, _)| i).collect::<Vec<_>>();
+    assert_eq!(result, vec![BigUint::from(0_u8), BigUint::from(1_u8), BigUint::from(2_u8)]);
+}
+
+#[cfg(all(feature = "num", feature = "rational"))]
+#[test]
+fn index_num() {
+    use num_rational::Ratio;
+    let result = (0..3).index_num::<Ratio<i32>>().map(|(i, _)| i).collect::<Vec<_>>();
+    assert_eq!(result, vec![Ratio::from_integer(0), Ratio::from_integer(1), Ratio::from_integer(2)]);
+    let result = (0..3).index_num_start(Ratio::new(1, 2)).last();
+    assert_eq!(result, Some((Ratio::new(5, 2), 2)));
+}
+
+#[test]
+fn index_duration() {
+    use std::time::Duration;
+    let step = Duration::from_micros(1_500_001);
+    let mut iter = (0..10_000).index_duration(Duration::from_secs(1), step);
+    assert_eq!(iter.next(), Some((Duration::from_secs(1), 0)));
+    assert_eq!(iter.nth(2), Some((Duration::from_secs(1) + step * 3, 3)));
+    assert_eq!(iter.next_back(), Some((Duration::from_secs(1) + step * 9999, 9999)));
+    assert_eq!(iter.len(), 9995);
+    assert_eq!(iter.next(), Some((Duration::from_secs(1) + step * 4, 4)));
+}
+
+#[test]
+#[should_panic(expected = "the index overflowed Duration")]
+fn index_duration_overflow() {
+    use std::time::Duration;
+    let _ = (0..3).index_duration(Duration::MAX, Duration::from_secs(1)).nth(1);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn index_dates() {
+    use chrono::{NaiveDate, TimeDelta};
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let mut iter = (0..5).index_dates(date(2023, 12, 31), TimeDelta::hours(12));
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.next(), Some((date(2023, 12, 31), 0)));
+    assert_eq!(iter.next(), Some((date(2023, 12, 31), 1)));
+    assert_eq!(iter.nth(1), Some((date(2024, 1, 1), 3)));
+    assert_eq!(iter.next_back(), Some((date(2024, 1, 2), 4)));
+
+    let result = (0..3).index_dates(date(2024, 1, 3), TimeDelta::weeks(-1)).map(|(d, _)| d).collect::<Vec<_>>();
+    assert_eq!(result, vec![date(2024, 1, 3), date(2023, 12, 27), date(2023, 12, 20)]);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn index_calendar() {
+    use time::{Date, Duration, Month, OffsetDateTime};
+    use crate::CalendarStep;
+    let date = |y, m, d| Date::from_calendar_date(y, m, d).unwrap();
+    let mut iter = (0..5).index_calendar(date(2023, Month::November, 30), CalendarStep::Months(-3));
+    assert_eq!(iter.next(), Some((date(2023, Month::November, 30), 0)));
+    assert_eq!(iter.next(), Some((date(2023, Month::August, 30), 1)));
+    assert_eq!(iter.next_back(), Some((date(2022, Month::November, 30), 4)));
+    assert_eq!(iter.nth(1), Some((date(2023, Month::February, 28), 3)));
+
+    let result = (0..3).index_calendar(date(2024, Month::February, 29), CalendarStep::years(1)).nth(2);
+    assert_eq!(result, Some((date(2026, Month::February, 28), 2)));
+
+    let start = OffsetDateTime::UNIX_EPOCH;
+    let mut iter = (0..3).index_calendar(start, CalendarStep::Duration(Duration::hours(36)));
+    assert_eq!(iter.nth(2), Some((start + Duration::hours(72), 2)));
+}
+
+#[test]
+fn index_nonzero() {
+    use std::num::NonZeroU8;
+    let nz = |v| NonZeroU8::new(v).unwrap();
+    let mut iter = (0..255).index_nonzero::<NonZeroU8>();
+    assert_eq!(iter.next(), Some((nz(1), 0)));
+    assert_eq!(iter.nth(1), Some((nz(3), 2)));
+    assert_eq!(iter.next_back(), Some((nz(255), 254)));
+    // the last index is valid even though the following one overflows
+    assert_eq!(iter.nth(250), Some((nz(254), 253)));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = (0..3).index_nonzero_step(nz(200), nz(50));
+    assert_eq!(iter.next(), Some((nz(200), 0)));
+    assert_eq!(iter.next(), Some((nz(250), 1)));
+}
+
+#[test]
+#[should_panic(expected = "the index overflowed")]
+fn index_nonzero_overflow() {
+    use std::num::NonZeroU8;
+    let _ = (0..3).index_nonzero_step(NonZeroU8::new(200).unwrap(), NonZeroU8::new(50).unwrap()).nth(2);
+}
+
+#[test]
+fn index_wrapping() {
+    use std::num::Wrapping;
+    let mut iter = (0..600).index_wrapping::<u8>();
+    assert_eq!(iter.nth(256), Some((Wrapping(0), 256)));
+    assert_eq!(iter.next(), Some((Wrapping(1), 257)));
+    assert_eq!(iter.next_back(), Some((Wrapping(87), 599)));
+    assert_eq!(iter.last(), Some((Wrapping(86), 598)));
+
+    let result = (0..4).index_step(Wrapping(u16::MAX - 1), Wrapping(1)).map(|(i, _)| i.0).collect::<Vec<_>>();
+    assert_eq!(result, vec![u16::MAX - 1, u16::MAX, 0, 1]);
+}
+
+// `Saturating<T>` is stable since Rust 1.74, above the MSRV
+#[cfg(has_saturating)]
+#[test]
+fn index_saturating_wrapper() {
+    use std::num::Saturating;
+    let mut iter = (0..10).index_step(Saturating(250_u8), Saturating(2));
+    assert_eq!(iter.next(), Some((Saturating(250), 0)));
+    assert_eq!(iter.nth(1), Some((Saturating(254), 2)));
+    assert_eq!(iter.next(), Some((Saturating(255), 3)));
+    assert_eq!(iter.next_back(), Some((Saturating(255), 9)));
+    assert_eq!(iter.map(|(i, _)| i.0).collect::<Vec<_>>(), vec![255; 5]);
+
+    let result = (0..3).index_step(Saturating(i8::MIN + 1), Saturating(-1)).map(|(i, _)| i.0).collect::<Vec<_>>();
+    assert_eq!(result, vec![i8::MIN + 1, i8::MIN, i8::MIN]);
+}
+
+#[test]
+fn impl_index_type() {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct RowId(u32);
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Offset(i16);
+    crate::impl_index_type!(RowId as u32, Offset as i16);
+
+    let mut iter = (0..10).index::<RowId>();
+    assert_eq!(iter.next(), Some((RowId(0), 0)));
+    assert_eq!(iter.nth(3), Some((RowId(4), 4)));
+    assert_eq!(iter.next_back(), Some((RowId(9), 9)));
+    assert_eq!(iter.try_nth(0), Ok(Some((RowId(5), 5))));
+    let result = (0..3).index_step(Offset(10), Offset(-5)).map(|(i, _)| i).collect::<Vec<_>>();
+    assert_eq!(result, vec![Offset(10), Offset(5), Offset(0)]);
+    assert_eq!(Offset::try_from(40000_usize).ok(), None);
+}
+
+#[test]
+fn index_step_with() {
+    use std::time::{Duration, Instant};
+    let start = Instant::now();
+    let step = Duration::from_millis(3);
+    let mut iter = (0..1000).index_step_with(start, step);
+    assert_eq!(iter.next(), Some((start, 0)));
+    assert_eq!(iter.nth(9), Some((start + step * 10, 10)));
+    assert_eq!(iter.next(), Some((start + step * 11, 11)));
+    assert_eq!(iter.next_back(), Some((start + step * 999, 999)));
+    assert_eq!(iter.len(), 987);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn index_step_with_dates() {
+    use chrono::{NaiveDate, TimeDelta};
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let result = (0..3).index_step_with(date(2024, 12, 30), TimeDelta::days(1)).map(|(d, _)| d).collect::<Vec<_>>();
+    assert_eq!(result, vec![date(2024, 12, 30), date(2024, 12, 31), date(2025, 1, 1)]);
+}
+
+#[test]
+fn index_const_step() {
+    let mut iter = (0..1000).index_const_step::<i16, 3>();
+    assert_eq!(iter.next(), Some((0, 0)));
+    assert_eq!(iter.nth(10), Some((33, 11)));
+    assert_eq!(iter.next_back(), Some((2997, 999)));
+    assert_eq!(iter.len(), 987);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_index() {
+    use rayon::prelude::*;
+    use crate::ParallelIndexerIterator;
+    let items = (0..10_000_u64).collect::<Vec<_>>();
+    let result = items.par_iter().with_min_len(7).index_step::<u64>(5, 3).collect::<Vec<_>>();
+    let expected = items.iter().index_step::<u64>(5, 3).collect::<Vec<_>>();
+    assert_eq!(result, expected);
+
+    let result = items.into_par_iter().index_start::<i32>(-100).rev().filter(|(i, _)| i % 1000 == 0).map(|(i, _)| i).collect::<Vec<_>>();
+    assert_eq!(result, (0..10).rev().map(|i| i * 1000).collect::<Vec<_>>());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_bridge_reorder() {
+    use rayon::prelude::*;
+    use crate::ParallelReorder;
+    let result = (0..1000).index_par_bridge::<u16>().map(|(i, x)| (i, x * 2)).reorder_by_index();
+    assert_eq!(result, (0..1000).map(|x| (x as u16, x * 2)).collect::<Vec<_>>());
+
+    let result = (0..100).index_par_bridge::<u8>().filter(|(i, _)| i % 10 == 0).reorder_by_index();
+    assert_eq!(result.iter().map(|(i, _)| *i).collect::<Vec<_>>(), vec![0, 10, 20, 30, 40, 50, 60, 70, 80, 90]);
+}
+
+#[cfg(feature = "stream")]
+#[test]
+fn stream_index() {
+    use futures::{executor::block_on, stream, Stream, StreamExt};
+    use crate::IndexerStreamExt;
+    let mut items = stream::iter('a'..='e').index_start::<u8>(1);
+    assert_eq!(items.size_hint(), (5, Some(5)));
+    assert_eq!(block_on(items.next()), Some((1, 'a')));
+    let result = block_on(items.skip(2).collect::<Vec<_>>());
+    assert_eq!(result, vec![(4, 'd'), (5, 'e')]);
+
+    let result = block_on(stream::iter(0..3).index::<i16>().fuse().collect::<Vec<_>>());
+    assert_eq!(result, vec![(0, 0), (1, 1), (2, 2)]);
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn receiver_index() {
+    use futures::{executor::block_on, Stream, StreamExt};
+    use tokio_stream::wrappers::ReceiverStream;
+    use crate::{IndexedReceiver, IndexerStreamExt};
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    for x in 0..4 {
+        tx.send(x * 10).unwrap();
+    }
+    drop(tx);
+    let mut items = rx.index_step::<u8>(1, 2);
+    assert_eq!(items.size_hint(), (4, Some(4)));
+    assert_eq!(block_on(items.next()), Some((1, 0)));
+    assert_eq!(items.size_hint(), (3, Some(3)));
+    assert_eq!(block_on(items.collect::<Vec<_>>()), vec![(3, 10), (5, 20), (7, 30)]);
+
+    let (tx, rx) = tokio::sync::mpsc::channel(4);
+    tx.try_send('a').unwrap();
+    let mut items = ReceiverStream::new(rx).index::<u32>();
+    assert_eq!(items.size_hint(), (1, None));
+    assert_eq!(block_on(items.next()), Some((0, 'a')));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn index_serde_state() {
+    use crate::CounterState;
+    let mut items = "abcdef".chars().index_step::<i64>(-10, 5);
+    assert_eq!(items.next(), Some((-10, 'a')));
+    assert_eq!(items.counter_state(), CounterState { start: -10, counter: -5, step: 5 });
+    let saved = serde_json::to_string(&items).unwrap();
+    assert_eq!(saved, r#"{"start":-10,"counter":-5,"step":5}"#);
+    let state: CounterState<i64> = serde_json::from_str(&saved).unwrap();
+    assert_eq!(serde_json::to_string(&state).unwrap(), saved);
+    let mut resumed = items.map(|(_, c)| c).index_resume(state);
+    assert_eq!(resumed.next(), Some((-5, 'b')));
+    assert_eq!(resumed.next(), Some((0, 'c')));
+    // the start of the original job is restored
+    resumed.reset();
+    assert_eq!(resumed.collect::<Vec<_>>(), vec![(-10, 'd'), (-5, 'e'), (0, 'f')]);
+    let fresh = (0..2).index_start::<u8>(7);
+    assert_eq!(fresh.counter_state(), CounterState { start: 7, counter: 7, step: 1 });
+}
+
+#[test]
+fn index_accessors() {
+    let mut items = (10..20).index_start::<i16>(-2);
+    assert_eq!(items.current_index(), &-2);
+    assert_eq!(items.step(), &1);
+    assert_eq!(items.nth(3), Some((1, 13)));
+    assert_eq!(items.current_index(), &2);
+    let mut rest = items.into_inner();
+    assert_eq!(rest.next(), Some(14));
+    assert_eq!(rest.len(), 5);
+}
+
+#[test]
+fn index_set_index_reset() {
+    let mut items = (0..8).index_step::<i32>(5, -1);
+    assert_eq!(items.next(), Some((5, 0)));
+    items.set_index(100);
+    assert_eq!(items.next(), Some((100, 1)));
+    assert_eq!(items.next(), Some((99, 2)));
+    items.reset();
+    assert_eq!(items.current_index(), &5);
+    assert_eq!(items.nth(1), Some((4, 4)));
+    items.set_index(0);
+    let mut items = items.rev();
+    assert_eq!(items.next(), Some((-2, 7)));
+
+    let mut items = (0..4).index_step::<usize>(3, 6).aligned_to(4);
+    assert_eq!(items.nth(2), Some((20, 2)));
+    items.reset();
+    assert_eq!(items.next(), Some((4, 3)));
+
+    let mut items = "abc".chars().index_start::<u8>(1);
+    items.reset();
+    items.set_index(10);
+    assert_eq!(items.next(), Some((10, 'a')));
+    items.reset();
+    assert_eq!(items.next(), Some((1, 'b')));
+    items.reset();
+    assert_eq!(items.next(), Some((1, 'c')));
+
+    // the constructors don't require the index to be cloned
+    struct NoClone;
+    let items = (0..2).index_step(NoClone, NoClone);
+    assert_eq!(items.into_inner().len(), 2);
+}
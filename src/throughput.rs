@@ -0,0 +1,110 @@
+// Copyright 2025 Redglyph
+//
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+//------------------------------------------------------------------------------
+
+/// A handle on the throughput measured by a [`ThroughputIter`] adapter.
+///
+/// The handle stays valid after the adapter has been moved into a loop, or dropped. Cloning it gives
+/// another handle to the same measurements. The handle is `Send` and `Sync`, so the measurements can
+/// be read by another thread, for instance a reporter thread, while the iteration runs.
+#[derive(Clone, Debug, Default)]
+pub struct Throughput {
+    state: Arc<ThroughputState>,
+}
+
+/// The bits of the rate when it hasn't been measured yet; it's a NaN, which isn't a possible rate.
+const NO_RATE: u64 = u64::MAX;
+
+#[derive(Debug)]
+struct ThroughputState {
+    count: AtomicU64,
+    // bits of the f64 rate, or NO_RATE
+    rate: AtomicU64,
+}
+
+impl Default for ThroughputState {
+    fn default() -> Self {
+        ThroughputState { count: AtomicU64::new(0), rate: AtomicU64::new(NO_RATE) }
+    }
+}
+
+impl Throughput {
+    /// Returns the rate in items per second measured over the last complete window, or `None`
+    /// if no window has been completed yet.
+    pub fn rate(&self) -> Option<f64> {
+        match self.state.rate.load(Ordering::Relaxed) {
+            NO_RATE => None,
+            bits => Some(f64::from_bits(bits)),
+        }
+    }
+
+    /// Returns the total number of items yielded so far.
+    pub fn count(&self) -> u64 {
+        self.state.count.load(Ordering::Relaxed)
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that passes the items of the source iterator through, while measuring the rate at
+/// which they're yielded.
+///
+/// This `struct` is created by the [`with_throughput`](crate::IndexerIterator::with_throughput)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ThroughputIter<I> {
+    iter: I,
+    window: Duration,
+    window_start: Option<Instant>,
+    window_count: u64,
+    meter: Throughput,
+}
+
+impl<I> ThroughputIter<I> {
+    pub fn new(iter: I, window: Duration) -> ThroughputIter<I> {
+        ThroughputIter { iter, window, window_start: None, window_count: 0, meter: Throughput::default() }
+    }
+
+    /// Returns a handle on the measured throughput.
+    pub fn meter(&self) -> Throughput {
+        self.meter.clone()
+    }
+}
+
+impl<I: Iterator> Iterator for ThroughputIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window_start = *self.window_start.get_or_insert_with(Instant::now);
+        let item = self.iter.next()?;
+        self.window_count += 1;
+        let state = &self.meter.state;
+        state.count.fetch_add(1, Ordering::Relaxed);
+        let now = Instant::now();
+        let elapsed = now.duration_since(window_start);
+        if elapsed >= self.window && !elapsed.is_zero() {
+            let rate = self.window_count as f64 / elapsed.as_secs_f64();
+            state.rate.store(rate.to_bits(), Ordering::Relaxed);
+            self.window_start = Some(now);
+            self.window_count = 0;
+        }
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for ThroughputIter<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
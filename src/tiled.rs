@@ -0,0 +1,78 @@
+// Copyright 2025 Redglyph
+//
+
+use core::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// An iterator that yields the tile coordinates of each item of a flat row-major buffer, as well
+/// as the item itself.
+///
+/// This `struct` is created by the [`index_tiled`](crate::IndexerIterator::index_tiled)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TiledIndexer<I> {
+    iter: I,
+    width: usize,
+    tile_w: usize,
+    tile_h: usize,
+    col: usize,
+    col_in_tile: usize,
+    tile_col: usize,
+    row_in_tile: usize,
+    tile_row: usize,
+}
+
+impl<I> TiledIndexer<I> {
+    pub fn new(iter: I, width: usize, tile_w: usize, tile_h: usize) -> TiledIndexer<I> {
+        assert!(width > 0 && tile_w > 0 && tile_h > 0, "the width and the tile dimensions must be greater than 0");
+        TiledIndexer { iter, width, tile_w, tile_h, col: 0, col_in_tile: 0, tile_col: 0, row_in_tile: 0, tile_row: 0 }
+    }
+
+    /// Moves the position to the next item, without any division.
+    fn advance(&mut self) {
+        self.col += 1;
+        if self.col == self.width {
+            self.col = 0;
+            self.col_in_tile = 0;
+            self.tile_col = 0;
+            self.row_in_tile += 1;
+            if self.row_in_tile == self.tile_h {
+                self.row_in_tile = 0;
+                self.tile_row += 1;
+            }
+        } else {
+            self.col_in_tile += 1;
+            if self.col_in_tile == self.tile_w {
+                self.col_in_tile = 0;
+                self.tile_col += 1;
+            }
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for TiledIndexer<I> {
+    type Item = ((usize, usize, usize), I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let offset = self.row_in_tile * self.tile_w + self.col_in_tile;
+        let result = Some(((self.tile_row, self.tile_col, offset), item));
+        self.advance();
+        result
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for TiledIndexer<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for TiledIndexer<I> {}
@@ -0,0 +1,125 @@
+// Copyright 2025 Redglyph
+//
+
+use core::ops::{Add, AddAssign};
+
+//------------------------------------------------------------------------------
+
+/// The arithmetic required from an index type by [`Indexer`](crate::Indexer) and the adapters
+/// built on it: the index must be cloned and added by reference.
+///
+/// The trait is implemented for every type `T` where `T: Clone + AddAssign<&T>` and `&T: Add<Output = T>`,
+/// which includes the primitive integer types, `Wrapping<T>`, `Saturating<T>` (from Rust 1.74) and the
+/// types made with [`impl_index_type!`](crate::impl_index_type). It's used as a bound in generic code
+/// instead of the operator bounds:
+///
+/// ```
+/// use iter_index::{IndexValue, IndexerIterator};
+///
+/// fn every_other<T: IndexValue>(start: T, step: T) -> Vec<T> {
+///     let step = step.add_ref(&step);
+///     (0..3).index_step(start, step).map(|(i, _)| i).collect()
+/// }
+///
+/// assert_eq!(every_other(1_u8, 1), vec![1, 3, 5]);
+/// ```
+///
+/// It's also the extension point for custom index types which don't implement those operators: a type
+/// which implements `IndexValue` directly can be used with [`Indexer`](crate::Indexer) and all the
+/// adapters which take an explicit start and step.
+///
+/// ```
+/// use iter_index::{IndexValue, IndexerIterator};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Version { major: u16, minor: u16 }
+///
+/// impl IndexValue for Version {
+///     fn add_ref(&self, rhs: &Self) -> Self {
+///         Version { major: self.major + rhs.major, minor: self.minor + rhs.minor }
+///     }
+///
+///     fn add_assign_ref(&mut self, rhs: &Self) {
+///         *self = self.add_ref(rhs);
+///     }
+/// }
+///
+/// let (start, step) = (Version { major: 1, minor: 0 }, Version { major: 0, minor: 1 });
+/// let mut releases = ["a", "b", "c"].into_iter().index_step(start.clone(), step.clone());
+/// assert_eq!(releases.nth(2), Some((Version { major: 1, minor: 2 }, "c")));
+///
+/// let mut notes = ["a", "b", "new", "c"].into_iter().index_step_reset_when(start, step, |s| *s == "new");
+/// assert_eq!(notes.nth(3), Some((Version { major: 1, minor: 1 }, "c")));
+/// ```
+pub trait IndexValue: Clone {
+    /// Returns `self + rhs`.
+    fn add_ref(&self, rhs: &Self) -> Self;
+
+    /// Adds `rhs` to `self`.
+    fn add_assign_ref(&mut self, rhs: &Self);
+}
+
+impl<T> IndexValue for T
+where
+    T: Clone + for<'a> AddAssign<&'a T>,
+    for<'a> &'a T: Add<Output=T>,
+{
+    #[inline]
+    fn add_ref(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+
+    #[inline]
+    fn add_assign_ref(&mut self, rhs: &Self) {
+        *self += rhs
+    }
+}
+
+/// Returns `counter + n * step`, using only additions so that `T` doesn't need to be convertible
+/// from `usize` or to support multiplications. The steps are added by powers of two, which takes
+/// O(log n) additions.
+///
+/// The intermediate values are either a power of two times `step`, at most `n * step / 2`, or between
+/// `counter` and the result, so the calculation doesn't overflow if the result fits in `T`, even when
+/// `n * step` doesn't.
+pub(crate) fn add_steps<T: IndexValue>(counter: &T, step: &T, n: usize) -> T {
+    add_steps_with(counter.clone(), step.clone(), n, |mut i, s| { i.add_assign_ref(s); i }, |s| s.add_ref(s))
+}
+
+/// Returns `counter + n * step` like [`add_steps`], for a step whose type may differ from the
+/// index type. `add` adds a step to the index, and `double` adds a step to itself.
+pub(crate) fn add_steps_with<T, S>(
+    counter: T,
+    step: S,
+    mut n: usize,
+    mut add: impl FnMut(T, &S) -> T,
+    mut double: impl FnMut(&S) -> S,
+) -> T {
+    let mut i = counter;
+    let mut power = step;
+    while n > 0 {
+        if n & 1 != 0 {
+            i = add(i, &power);
+        }
+        n >>= 1;
+        if n == 1 {
+            // the last power is added twice rather than calculated, since it may not fit in the step type
+            i = add(i, &power);
+            i = add(i, &power);
+            break;
+        }
+        if n > 0 {
+            power = double(&power);
+        }
+    }
+    i
+}
+
+/// Converts a position or a distance counted in `usize` to the index type.
+///
+/// # Panics
+///
+/// The function panics if `n` doesn't fit in `T`.
+pub(crate) fn to_index<T: TryFrom<usize>>(n: usize) -> T {
+    n.try_into().unwrap_or_else(|_| panic!("Cannot convert n = {n} into {}", core::any::type_name::<T>()))
+}
@@ -0,0 +1,163 @@
+// Copyright 2025 Redglyph
+//
+
+use std::iter::FusedIterator;
+
+//------------------------------------------------------------------------------
+
+/// A set of visited indices, recorded by a [`TrackVisited`] adapter.
+///
+/// The set is a [`roaring::RoaringBitmap`] when the `roaring` feature is enabled, and a plain bit
+/// vector otherwise.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Visited {
+    #[cfg(feature = "roaring")]
+    bits: roaring::RoaringBitmap,
+    #[cfg(not(feature = "roaring"))]
+    bits: Vec<u64>,
+}
+
+impl Visited {
+    /// Creates an empty set.
+    pub fn new() -> Visited {
+        Visited::default()
+    }
+
+    /// Adds `index` to the set, and returns `true` if it wasn't already in it.
+    #[cfg(feature = "roaring")]
+    pub fn insert(&mut self, index: u32) -> bool {
+        self.bits.insert(index)
+    }
+
+    /// Adds `index` to the set, and returns `true` if it wasn't already in it.
+    #[cfg(not(feature = "roaring"))]
+    pub fn insert(&mut self, index: u32) -> bool {
+        let (word, mask) = (index as usize / 64, 1 << (index % 64));
+        if word >= self.bits.len() {
+            self.bits.resize(word + 1, 0);
+        }
+        let is_new = self.bits[word] & mask == 0;
+        self.bits[word] |= mask;
+        is_new
+    }
+
+    /// Returns `true` if `index` is in the set.
+    #[cfg(feature = "roaring")]
+    pub fn contains(&self, index: u32) -> bool {
+        self.bits.contains(index)
+    }
+
+    /// Returns `true` if `index` is in the set.
+    #[cfg(not(feature = "roaring"))]
+    pub fn contains(&self, index: u32) -> bool {
+        self.bits.get(index as usize / 64).map_or(false, |w| w & (1 << (index % 64)) != 0)
+    }
+
+    /// Returns the number of indices in the set.
+    #[cfg(feature = "roaring")]
+    pub fn len(&self) -> u64 {
+        self.bits.len()
+    }
+
+    /// Returns the number of indices in the set.
+    #[cfg(not(feature = "roaring"))]
+    pub fn len(&self) -> u64 {
+        self.bits.iter().map(|w| u64::from(w.count_ones())).sum()
+    }
+
+    /// Returns `true` if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the indices in the set, in ascending order.
+    #[cfg(feature = "roaring")]
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.bits.iter()
+    }
+
+    /// Returns an iterator over the indices in the set, in ascending order.
+    #[cfg(not(feature = "roaring"))]
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.bits.iter().enumerate().flat_map(|(word, &bits)| {
+            (0..64).filter(move |bit| bits & (1 << bit) != 0).map(move |bit| (word * 64 + bit) as u32)
+        })
+    }
+
+    /// Returns an iterator over the indices lower than `end` which are not in the set, in ascending order.
+    pub fn missing(&self, end: u32) -> impl Iterator<Item = u32> + '_ {
+        (0..end).filter(move |&i| !self.contains(i))
+    }
+
+    /// Returns the underlying bitmap.
+    #[cfg(feature = "roaring")]
+    pub fn into_bitmap(self) -> roaring::RoaringBitmap {
+        self.bits
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// An iterator that passes the indexed items of the source iterator through, while recording
+/// their index in a [`Visited`] set.
+///
+/// This `struct` is created by the [`track_visited`](crate::IndexerIterator::track_visited)
+/// method on [`IndexerIterator`](crate::IndexerIterator).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TrackVisited<I> {
+    iter: I,
+    visited: Visited,
+}
+
+impl<I> TrackVisited<I> {
+    pub fn new(iter: I) -> TrackVisited<I> {
+        TrackVisited { iter, visited: Visited::new() }
+    }
+
+    /// Returns the set of the indices yielded so far.
+    pub fn visited(&self) -> &Visited {
+        &self.visited
+    }
+
+    /// Consumes the adapter and returns the set of the indices yielded.
+    pub fn into_visited(self) -> Visited {
+        self.visited
+    }
+}
+
+impl<I, T, V> Iterator for TrackVisited<I>
+where
+    I: Iterator<Item = (T, V)>,
+    T: Clone + TryInto<u32>,
+{
+    type Item = (T, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, v) = self.iter.next()?;
+        let index = i.clone().try_into().unwrap_or_else(|_| panic!("Cannot convert index into u32"));
+        self.visited.insert(index);
+        Some((i, v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, V> ExactSizeIterator for TrackVisited<I>
+where
+    I: ExactSizeIterator<Item = (T, V)>,
+    T: Clone + TryInto<u32>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T, V> FusedIterator for TrackVisited<I>
+where
+    I: FusedIterator<Item = (T, V)>,
+    T: Clone + TryInto<u32>,
+{}
@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use iter_index::IndexerIterator;
 
 #[test]
@@ -22,3 +24,25 @@ fn tests() {
     assert_eq!(result.nth(5), Some((160, 'g')));
 }
 
+
+#[test]
+fn collections() {
+    use std::collections::VecDeque;
+    use iter_index::IndexedCollection;
+
+    let items = vec!["a", "b", "c"];
+    let result = (&items).index::<u8>().collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, &"a"), (1, &"b"), (2, &"c")]);
+
+    let result = items.index_step::<i16>(100, 10).rev().collect::<Vec<_>>();
+    assert_eq!(result, vec![(120, "c"), (110, "b"), (100, "a")]);
+
+    let result = [1.5, 2.5].index_start::<u32>(1).collect::<Vec<_>>();
+    assert_eq!(result, vec![(1, 1.5), (2, 2.5)]);
+
+    let mut queue = VecDeque::from([1, 2, 3]);
+    for (i, x) in (&mut queue).index::<i32>() {
+        *x *= i;
+    }
+    assert_eq!(queue, VecDeque::from([0, 2, 6]));
+}